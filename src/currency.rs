@@ -1,6 +1,10 @@
-use crate::CurrencyAmount;
+use crate::{CodeError, CurrencyAmount, Error, Money, AMOUNT_UNIT};
+use std::borrow::Borrow;
 use std::collections::HashMap;
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
 
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
@@ -25,6 +29,45 @@ impl Exponent {
     pub fn new(amount: CurrencyAmount, exponent: u8) -> Self {
         Exponent { amount, exponent }
     }
+
+    /// The amount of this `Exponent` expressed with a greater or equal `exponent`.
+    fn amount_at(self, exponent: u8) -> CurrencyAmount {
+        self.amount * 10i128.pow(u32::from(exponent - self.exponent)).into()
+    }
+}
+
+/// Adds two `Exponent`s. The result has the greater of the two exponents, so that
+/// no precision is lost: `1.5 + 0.25` is `175` with exponent `2`.
+impl std::ops::Add for Exponent {
+    type Output = Exponent;
+    fn add(self, other: Exponent) -> Exponent {
+        let exponent = self.exponent.max(other.exponent);
+        Exponent::new(
+            self.amount_at(exponent) + other.amount_at(exponent),
+            exponent,
+        )
+    }
+}
+
+/// Subtracts two `Exponent`s. The result has the greater of the two exponents, so that
+/// no precision is lost.
+impl std::ops::Sub for Exponent {
+    type Output = Exponent;
+    fn sub(self, other: Exponent) -> Exponent {
+        let exponent = self.exponent.max(other.exponent);
+        Exponent::new(
+            self.amount_at(exponent) - other.amount_at(exponent),
+            exponent,
+        )
+    }
+}
+
+/// Scales an `Exponent` by an integer. The exponent is kept, so no precision is lost.
+impl std::ops::Mul<i128> for Exponent {
+    type Output = Exponent;
+    fn mul(self, factor: i128) -> Exponent {
+        Exponent::new(self.amount * factor.into(), self.exponent)
+    }
 }
 
 impl PartialEq for Exponent {
@@ -39,17 +82,64 @@ impl PartialEq for Exponent {
     }
 }
 
+/// An `Exponent` is displayed as the decimal value `amount / 10^exponent`,
+/// without trailing zeros:
+///
+/// ```
+/// use monet::Exponent;
+///
+/// assert_eq!(&format!("{}", Exponent::new(1_000.into(), 2)), "10");
+/// assert_eq!(&format!("{}", Exponent::new(1_050.into(), 2)), "10.5");
+/// assert_eq!(&format!("{}", Exponent::new((-5).into(), 2)), "-0.05");
+///
+/// ```
+impl fmt::Display for Exponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scale = 10i128.pow(u32::from(self.exponent));
+        let sign = if *self.amount < 0 { "-" } else { "" };
+        let units = (*self.amount / scale).abs();
+        let decimals = (*self.amount % scale).abs();
+
+        if decimals == 0 {
+            write!(f, "{}{}", sign, units)
+        } else {
+            let decimals = format!("{:0width$}", decimals, width = usize::from(self.exponent));
+            write!(f, "{}{}.{}", sign, units, decimals.trim_end_matches('0'))
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Rates {
     map: HashMap<CurrencyCode, CurrencyAmount>,
+    base: Option<CurrencyCode>,
+    extra_digits: u8,
 }
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(try_from = "RawCurrencyCode"))]
 pub struct CurrencyCode {
     code: [u8; 3],
 }
 
+/// The serialized form of a `CurrencyCode`, validated like `TryFrom<&[u8]>` when deserializing.
+#[cfg(feature = "serialize")]
+#[derive(Deserialize)]
+#[serde(rename = "CurrencyCode")]
+struct RawCurrencyCode {
+    code: [u8; 3],
+}
+
+#[cfg(feature = "serialize")]
+impl TryFrom<RawCurrencyCode> for CurrencyCode {
+    type Error = Error;
+
+    fn try_from(raw: RawCurrencyCode) -> Result<Self, Self::Error> {
+        CurrencyCode::try_from(&raw.code[..])
+    }
+}
+
 impl std::ops::Deref for CurrencyCode {
     type Target = [u8; 3];
 
@@ -58,6 +148,70 @@ impl std::ops::Deref for CurrencyCode {
     }
 }
 
+/// A `CurrencyCode` can be viewed as a `&str`. Codes are checked to be ASCII letters when
+/// created, parsed or deserialized, so they are always valid UTF-8.
+impl AsRef<str> for CurrencyCode {
+    fn as_ref(&self) -> &str {
+        std::str::from_utf8(&self.code).unwrap_or("")
+    }
+}
+
+/// Allows looking up a `CurrencyCode` key with a `&str`, e.g. in `Rates::as_map`.
+impl Borrow<str> for CurrencyCode {
+    fn borrow(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// Hashes like the `&str` it can be borrowed as.
+impl Hash for CurrencyCode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+impl CurrencyCode {
+    /// Creates a `CurrencyCode` from a byte string literal, in a const context.
+    /// The length is checked at compile time by the array type. The content must be ASCII
+    /// letters, which is checked at compile time when used in a const and panics otherwise.
+    ///
+    /// ```
+    /// use monet::CurrencyCode;
+    /// use std::convert::TryInto;
+    ///
+    /// const USD: CurrencyCode = CurrencyCode::from_static(b"USD");
+    ///
+    /// assert_eq!(Ok(USD), "USD".try_into());
+    ///
+    /// ```
+    pub const fn from_static(code: &'static [u8; 3]) -> CurrencyCode {
+        let mut i = 0;
+        while i < code.len() {
+            assert!(
+                code[i].is_ascii_alphabetic(),
+                "Currency codes must be ASCII letters"
+            );
+            i += 1;
+        }
+
+        CurrencyCode { code: *code }
+    }
+
+    /// Creates a `CurrencyCode` like `try_from` on a `&str`, but ignores
+    /// the whitespace surrounding the code.
+    ///
+    /// ```
+    /// use monet::CurrencyCode;
+    /// use std::convert::TryInto;
+    ///
+    /// assert_eq!(CurrencyCode::try_from_trimmed(" USD\t"), "USD".try_into());
+    ///
+    /// ```
+    pub fn try_from_trimmed(s: &str) -> crate::Result<CurrencyCode> {
+        CurrencyCode::try_from(s.trim())
+    }
+}
+
 impl Rates {
     /// Creates a new Rates struct and populates it from
     pub fn new() -> Self {
@@ -74,8 +228,179 @@ impl Rates {
     // }
 
     /// Construct a Rates struct with given rates.
+    ///
+    /// # Warning!
+    ///
+    /// Worths aren't validated: conversions into a currency worth `0` fail with
+    /// `Error::InvalidRate`, and a negative worth flips the sign of converted amounts.
+    /// See [`try_with_rates`](#method.try_with_rates).
     pub fn with_rates(map: HashMap<CurrencyCode, CurrencyAmount>) -> Self {
-        Rates { map }
+        Rates {
+            map,
+            base: None,
+            extra_digits: 0,
+        }
+    }
+
+    /// Construct a Rates struct with given rates, checking that every worth is strictly positive.
+    /// Returns `Error::InvalidRate` otherwise.
+    pub fn try_with_rates(map: HashMap<CurrencyCode, CurrencyAmount>) -> crate::Result<Self> {
+        for (code, worth) in &map {
+            if **worth <= 0 {
+                return Err(Error::InvalidRate(*code));
+            }
+        }
+
+        Ok(Rates::with_rates(map))
+    }
+
+    /// Construct a Rates struct with given rates, relative to the `base` currency.
+    /// The `base` currency is worth [`AMOUNT_UNIT`](constant.AMOUNT_UNIT.html) even if it's
+    /// missing from the map, as FX feeds usually omit it.
+    /// If `map` explicitly contains the `base` currency, its worth is used instead.
+    pub fn with_base(map: HashMap<CurrencyCode, CurrencyAmount>, base: CurrencyCode) -> Self {
+        Rates {
+            map,
+            base: Some(base),
+            extra_digits: 0,
+        }
+    }
+
+    /// Construct a Rates struct relative to the `base` currency from floating-point market quotes.
+    /// A quote is how many units of a currency make one unit of `base` (e.g. `("CHF", 0.8)` with
+    /// `USD` as base means that 1 USD buys 0.8 CHF), so its worth is `AMOUNT_UNIT / quote`,
+    /// rounded to the nearest integer. See [`with_base`](#method.with_base).
+    ///
+    /// Returns `Error::MalformedCode` for an invalid code and `Error::InvalidRate` for a quote that
+    /// is not finite, not positive, or whose worth is too small or too big to be represented.
+    pub fn from_quotes(quotes: &[(&str, f64)], base: &str) -> crate::Result<Rates> {
+        let base = base.try_into()?;
+        let mut map = HashMap::new();
+
+        for (code, quote) in quotes {
+            let code: CurrencyCode = (*code).try_into()?;
+            let worth = (AMOUNT_UNIT as f64 / quote).round();
+
+            // `i128::MAX as f64` rounds up to 2^127, which doesn't fit in an `i128`
+            if !quote.is_finite()
+                || *quote <= 0.0
+                || !worth.is_finite()
+                || worth < 1.0
+                || worth >= i128::MAX as f64
+            {
+                return Err(Error::InvalidRate(code));
+            }
+
+            map.insert(code, CurrencyAmount::from(worth as i128));
+        }
+
+        Ok(Rates::with_base(map, base))
+    }
+
+    /// Construct a Rates struct from `(code, worth)` pairs, where `code` is a `&str`.
+    /// Fails at the first code that can't be converted into a `CurrencyCode`.
+    pub fn try_from_iter<'s, I>(iter: I) -> crate::Result<Self>
+    where
+        I: IntoIterator<Item = (&'s str, i128)>,
+    {
+        iter.into_iter()
+            .map(|(code, worth)| Ok((code.try_into()?, worth.into())))
+            .collect()
+    }
+
+    /// Get the map of worths of these rates.
+    /// An implicit base currency (see [`with_base`](#method.with_base)) is not part of it.
+    pub fn as_map(&self) -> &HashMap<CurrencyCode, CurrencyAmount> {
+        &self.map
+    }
+
+    /// Consume these rates, returning their map of worths.
+    /// An implicit base currency (see [`with_base`](#method.with_base)) is not part of it.
+    pub fn into_inner(self) -> HashMap<CurrencyCode, CurrencyAmount> {
+        self.map
+    }
+
+    /// List the differences between these rates and `other` rates, as
+    /// `(code, worth in self, worth in other)`: added codes have no worth in `self`, removed
+    /// ones have no worth in `other`. Unchanged codes are omitted. The list is sorted by code.
+    /// Only the maps are compared (see [`as_map`](#method.as_map)).
+    pub fn diff(
+        &self,
+        other: &Rates,
+    ) -> Vec<(CurrencyCode, Option<CurrencyAmount>, Option<CurrencyAmount>)> {
+        let old = self.as_map();
+        let new = other.as_map();
+
+        let mut diff: Vec<_> = old
+            .keys()
+            .chain(new.keys().filter(|code| !old.contains_key(*code)))
+            .map(|code| (*code, old.get(code).cloned(), new.get(code).cloned()))
+            .filter(|(_, old_worth, new_worth)| old_worth != new_worth)
+            .collect();
+
+        diff.sort_by_key(|(code, _, _)| code.code);
+        diff
+    }
+
+    /// Check that converting one unit of every currency into every other currency and back
+    /// gives the original amount, within `tolerance`.
+    /// Returns the `(from, to)` pairs exceeding it, sorted by code. Pairs involving a
+    /// non-positive worth, or whose conversion overflows, fail as well.
+    ///
+    /// Rounding errors grow when worths are far apart: a currency worth much more than another
+    /// might not even be representable once converted.
+    pub fn verify_round_trip(
+        &self,
+        tolerance: CurrencyAmount,
+    ) -> Result<(), Vec<(CurrencyCode, CurrencyCode)>> {
+        let unit = CurrencyAmount::from(AMOUNT_UNIT);
+        let mut codes: Vec<_> = self.map.keys().cloned().collect();
+        codes.sort_by_key(|code| code.code);
+
+        let mut failing = Vec::new();
+
+        for &from in &codes {
+            for &to in codes.iter().filter(|&&to| to != from) {
+                // Converting with a non-positive worth divides by zero or flips the sign
+                if *self.map[&from] <= 0 || *self.map[&to] <= 0 {
+                    failing.push((from, to));
+                    continue;
+                }
+
+                let back = self
+                    .convert_amount(unit, from, to)
+                    .and_then(|converted| self.convert_amount(converted, to, from));
+
+                match back {
+                    Ok(back) if (*back - *unit).abs() <= *tolerance => (),
+                    _ => failing.push((from, to)),
+                }
+            }
+        }
+
+        if failing.is_empty() {
+            Ok(())
+        } else {
+            Err(failing)
+        }
+    }
+
+    /// Set the intermediate precision of conversions.
+    ///
+    /// By default, a conversion computes `amount * worth_from / worth_to` and truncates the
+    /// result. With `extra_digits > 0`, the division is performed on an amount scaled up by
+    /// `10^extra_digits`, which is then rounded (half away from zero) while scaling back.
+    ///
+    /// # Warning!
+    ///
+    /// The scaled intermediate value is `amount * worth_from * 10^extra_digits`, which overflows
+    /// `i128` much sooner for big amounts or worths. Conversions return `Error::Overflow` then,
+    /// which is always the case with `extra_digits` of `39` or more.
+    pub fn with_precision(self, extra_digits: u8) -> Self {
+        Rates {
+            extra_digits,
+            ..self
+        }
     }
 
     /// Get the worth of a currency as an `CurrencyAmount`.
@@ -83,18 +408,265 @@ impl Rates {
     /// If a USD is worth `1_000_000` and a CHF is worth `2_000_000`, that means that 2 USD are
     /// needed to make 1 CHF.
     pub fn worth(&self, code: CurrencyCode) -> Option<CurrencyAmount> {
-        self.map.get(&code).cloned()
+        self.map.get(&code).cloned().or_else(|| {
+            if self.base == Some(code) {
+                Some(AMOUNT_UNIT.into())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Get the worth of a currency given by a string code, which may be surrounded by whitespace
+    /// and be in any case: `" usd "` is looked up as `USD`.
+    /// Returns an error if the code is malformed or if no rate is defined for it.
+    pub fn worth_str(&self, code: &str) -> crate::Result<CurrencyAmount> {
+        let code = CurrencyCode::try_from_trimmed(&code.to_ascii_uppercase())?;
+        self.worth(code).ok_or(Error::RateNotFound(code))
+    }
+
+    /// Get the inverse worth of a currency, i.e. how much of this currency makes one base unit.
+    /// The result is computed as `AMOUNT_UNIT^2 / worth`, so that it stays in fixed point.
+    ///
+    /// # Precision
+    ///
+    /// The division truncates: if a CHF is worth `1_100_000`, its inverse worth is `909_090`
+    /// instead of `909_090.90...`. Multiplying the inverse worth back won't always give the
+    /// original worth.
+    ///
+    /// Returns `Error::RateNotFound` if `code` has no rate and `Error::InvalidRate` if its worth
+    /// is zero.
+    pub fn inverse_worth(&self, code: CurrencyCode) -> crate::Result<CurrencyAmount> {
+        let worth = self.worth(code).ok_or(Error::RateNotFound(code))?;
+
+        if *worth == 0 {
+            return Err(Error::InvalidRate(code));
+        }
+
+        Ok(CurrencyAmount::from(AMOUNT_UNIT * AMOUNT_UNIT) / worth)
+    }
+
+    /// Convert an `amount` of currency `from` into currency `to`.
+    ///
+    /// Every worth is relative to the same base currency, so the conversion triangulates
+    /// through it: `amount` is first expressed in the base currency (`amount * worth(from)`)
+    /// and then in the target currency (`/ worth(to)`). The base currency doesn't need to be
+    /// part of the rates.
+    ///
+    /// Returns `Error::RateNotFound` if either `from` or `to` has no rate and `Error::Overflow`
+    /// if the conversion doesn't fit in a `CurrencyAmount`.
+    pub fn convert_amount(
+        &self,
+        amount: CurrencyAmount,
+        from: CurrencyCode,
+        to: CurrencyCode,
+    ) -> crate::Result<CurrencyAmount> {
+        let worth_from = self.worth(from).ok_or(Error::RateNotFound(from))?;
+        let worth_to = self.worth(to).ok_or(Error::RateNotFound(to))?;
+
+        self.convert_with_worths(amount, worth_from, to, worth_to)
+    }
+
+    /// Convert `amount` from a currency worth `worth_from` into the currency `to` worth
+    /// `worth_to`, respecting the intermediate precision.
+    /// Returns `Error::InvalidRate(to)` if `worth_to` is zero, which the unchecked constructors
+    /// accept.
+    pub(crate) fn convert_with_worths(
+        &self,
+        amount: CurrencyAmount,
+        worth_from: CurrencyAmount,
+        to: CurrencyCode,
+        worth_to: CurrencyAmount,
+    ) -> crate::Result<CurrencyAmount> {
+        if *worth_to == 0 {
+            return Err(Error::InvalidRate(to));
+        }
+
+        if self.extra_digits == 0 {
+            return mul_div(*amount, *worth_from, *worth_to)
+                .map(CurrencyAmount::from)
+                .ok_or(Error::Overflow);
+        }
+
+        let scale = 10i128
+            .checked_pow(u32::from(self.extra_digits))
+            .ok_or(Error::Overflow)?;
+        let scaled = amount
+            .checked_mul(scale)
+            .and_then(|amount| mul_div(amount, *worth_from, *worth_to))
+            .ok_or(Error::Overflow)?;
+        let half = scale / 2 * scaled.signum();
+
+        scaled
+            .checked_add(half)
+            .map(|rounded| (rounded / scale).into())
+            .ok_or(Error::Overflow)
+    }
+
+    /// Convert every `Money` in `items` into currency `to`.
+    /// The worth of `to` is looked up only once.
+    ///
+    /// Returns `Error::RateNotFound` for the first currency having no rate and `Error::Overflow`
+    /// for the first conversion that doesn't fit in a `CurrencyAmount`.
+    pub fn convert_many(&self, items: &[Money], to: CurrencyCode) -> crate::Result<Vec<Money>> {
+        let worth_to = self.worth(to).ok_or(Error::RateNotFound(to))?;
+
+        items
+            .iter()
+            .map(|money| {
+                let worth_from = self
+                    .worth(money.currency_code)
+                    .ok_or(Error::RateNotFound(money.currency_code))?;
+
+                Ok(Money::new(
+                    self.convert_with_worths(money.amount, worth_from, to, worth_to)?,
+                    to,
+                ))
+            })
+            .collect()
+    }
+
+    /// The worth of one unit of `base` expressed in every other currency of these rates, as
+    /// `(code, amount)` sorted by code. The implicit base currency (see
+    /// [`with_base`](#method.with_base)) is included.
+    /// Returns an empty list if `base` has no rate. Currencies whose amount doesn't fit in a
+    /// `CurrencyAmount` are left out.
+    pub fn pairs_from(&self, base: CurrencyCode) -> Vec<(CurrencyCode, CurrencyAmount)> {
+        let worth_from = match self.worth(base) {
+            Some(worth) => worth,
+            None => return Vec::new(),
+        };
+
+        let mut pairs: Vec<_> = self
+            .map
+            .iter()
+            .map(|(code, worth)| (*code, *worth))
+            .chain(self.base.map(|code| (code, AMOUNT_UNIT.into())))
+            .filter(|(code, _)| *code != base)
+            .filter_map(|(code, worth_to)| {
+                self.convert_with_worths(AMOUNT_UNIT.into(), worth_from, code, worth_to)
+                    .ok()
+                    .map(|amount| (code, amount))
+            })
+            .collect();
+
+        pairs.sort_by_key(|(code, _)| code.code);
+        pairs.dedup_by_key(|(code, _)| *code);
+        pairs
+    }
+}
+
+/// Computes `a * b / c` truncated towards zero, like `i128` arithmetic, but with a 256 bit
+/// intermediate product: with the `high-precision` feature, amounts and worths are big enough
+/// for `a * b` to overflow even though the result fits.
+/// Returns `None` if `c` is zero or if the result doesn't fit in an `i128`.
+fn mul_div(a: i128, b: i128, c: i128) -> Option<i128> {
+    const LOW: u128 = u64::MAX as u128;
+
+    if c == 0 {
+        return None;
+    }
+
+    let negative = (a < 0) ^ (b < 0) ^ (c < 0);
+    let (a, b, c) = (a.unsigned_abs(), b.unsigned_abs(), c.unsigned_abs());
+
+    // Multiply the 64 bit halves of `a` and `b` into the 128 bit halves of the product
+    let (a_high, a_low, b_high, b_low) = (a >> 64, a & LOW, b >> 64, b & LOW);
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let middle = (low_low >> 64) + (low_high & LOW) + (high_low & LOW);
+
+    let low = (low_low & LOW) | (middle << 64);
+    let mut remainder = a_high * b_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+
+    if remainder >= c {
+        return None;
+    }
+
+    // Long division of the product by `c`, one bit of `low` at a time
+    let mut quotient = 0u128;
+
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+
+        if carry == 1 || remainder >= c {
+            remainder = remainder.wrapping_sub(c);
+            quotient |= 1 << bit;
+        }
+    }
+
+    let quotient = i128::try_from(quotient).ok()?;
+    Some(if negative { -quotient } else { quotient })
+}
+
+/// Collect `(code, worth)` pairs into `Rates`:
+///
+/// ```
+/// use monet::{CurrencyAmount, Rates};
+/// use std::convert::TryInto;
+///
+/// let rates: Rates = vec![("USD", 1_000_000), ("CHF", 1_100_000)]
+///     .into_iter()
+///     .map(|(code, worth)| (code.try_into().unwrap(), CurrencyAmount::from(worth)))
+///     .collect();
+///
+/// assert_eq!(rates.worth("CHF".try_into().unwrap()), Some(1_100_000.into()));
+///
+/// ```
+impl FromIterator<(CurrencyCode, CurrencyAmount)> for Rates {
+    fn from_iter<I: IntoIterator<Item = (CurrencyCode, CurrencyAmount)>>(iter: I) -> Self {
+        Rates::with_rates(iter.into_iter().collect())
+    }
+}
+
+/// Get the symbol commonly used for a currency code, if there is one.
+pub(crate) fn symbol(code: &str) -> Option<&'static str> {
+    match code {
+        "USD" => Some("$"),
+        "EUR" => Some("€"),
+        "GBP" => Some("£"),
+        "JPY" | "CNY" => Some("¥"),
+        "CHF" => Some("Fr."),
+        "INR" => Some("₹"),
+        "KRW" => Some("₩"),
+        "RUB" => Some("₽"),
+        "ILS" => Some("₪"),
+        "TRY" => Some("₺"),
+        "NGN" => Some("₦"),
+        "VND" => Some("₫"),
+        "PHP" => Some("₱"),
+        "UAH" => Some("₴"),
+        "BRL" => Some("R$"),
+        _ => None,
     }
 }
 
 impl<'s> TryFrom<&'s str> for CurrencyCode {
-    type Error = String;
+    type Error = Error;
 
+    /// Creates a `CurrencyCode` from a string of three ASCII letters.
     fn try_from(s: &'s str) -> Result<Self, Self::Error> {
-        if s.len() != 3 {
-            Err(format!("Given string has not length 3: \"{}\"", s))
+        s.as_bytes().try_into()
+    }
+}
+
+impl<'b> TryFrom<&'b [u8]> for CurrencyCode {
+    type Error = Error;
+
+    /// Creates a `CurrencyCode` from three bytes of ASCII letters, such as a field of a binary
+    /// record. Invalid bytes are reported lossily converted to a string.
+    fn try_from(bytes: &'b [u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != 3 {
+            Err(Error::MalformedCode(CodeError::WrongLength(
+                String::from_utf8_lossy(bytes).into_owned(),
+            )))
+        } else if !bytes.iter().all(u8::is_ascii_alphabetic) {
+            Err(Error::MalformedCode(CodeError::NotLetters(
+                String::from_utf8_lossy(bytes).into_owned(),
+            )))
         } else {
-            let bytes = s.as_bytes();
             Ok(CurrencyCode {
                 code: [bytes[0], bytes[1], bytes[2]],
             })
@@ -102,9 +674,708 @@ impl<'s> TryFrom<&'s str> for CurrencyCode {
     }
 }
 
+impl TryFrom<u16> for CurrencyCode {
+    type Error = Error;
+
+    /// Creates a `CurrencyCode` from an ISO 4217 numeric code, such as `756` for `CHF`.
+    fn try_from(numeric: u16) -> Result<Self, Self::Error> {
+        iso4217::num(&format!("{:03}", numeric))
+            .ok_or(Error::MalformedCode(CodeError::UnknownNumeric(numeric)))?
+            .alpha3
+            .try_into()
+    }
+}
+
 impl<'a> TryFrom<&'a CurrencyCode> for &'a str {
     type Error = std::str::Utf8Error;
     fn try_from(code: &'a CurrencyCode) -> Result<Self, Self::Error> {
         std::str::from_utf8(&code[..])
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    mod exponent {
+        use crate::Exponent;
+
+        #[test]
+        fn test_display() {
+            let exponent_a = Exponent::new(1_000.into(), 2);
+            let exponent_b = Exponent::new(10.into(), 0);
+
+            assert_eq!(format!("{}", exponent_a), "10".to_string());
+            assert_eq!(format!("{}", exponent_a), format!("{}", exponent_b));
+            assert_eq!(
+                format!("{}", Exponent::new(1_005.into(), 2)),
+                "10.05".to_string()
+            );
+        }
+
+        #[test]
+        fn test_add() {
+            let sum = Exponent::new(15.into(), 1) + Exponent::new(25.into(), 2);
+
+            assert_eq!(sum.amount, 175.into());
+            assert_eq!(sum.exponent, 2);
+            assert_eq!(
+                Exponent::new(3.into(), 0) + Exponent::new(2_000.into(), 3),
+                Exponent::new(5.into(), 0)
+            );
+        }
+
+        #[test]
+        fn test_sub() {
+            let difference = Exponent::new(1.into(), 0) - Exponent::new(25.into(), 2);
+
+            assert_eq!(difference.amount, 75.into());
+            assert_eq!(difference.exponent, 2);
+            assert_eq!(
+                Exponent::new(2.into(), 0) - Exponent::new(50.into(), 1),
+                Exponent::new((-3).into(), 0)
+            );
+        }
+
+        #[test]
+        fn test_mul() {
+            let product = Exponent::new(125.into(), 2) * 4;
+
+            assert_eq!(product.amount, 500.into());
+            assert_eq!(product.exponent, 2);
+            assert_eq!(product, Exponent::new(5.into(), 0));
+            assert_eq!(format!("{}", Exponent::new(15.into(), 1) * -3), "-4.5");
+        }
+    }
+
+    mod currency_code {
+        use crate::{CodeError, CurrencyCode, Error};
+        use std::collections::HashMap;
+        use std::convert::{TryFrom, TryInto};
+
+        const CHF: CurrencyCode = CurrencyCode::from_static(b"CHF");
+
+        #[test]
+        fn test_try_from_str() {
+            assert_eq!("CHF".try_into(), Ok(CHF));
+            assert_eq!(
+                CurrencyCode::try_from("CHFX"),
+                Err(Error::MalformedCode(CodeError::WrongLength(
+                    "CHFX".to_string()
+                )))
+            );
+            assert_eq!(
+                CurrencyCode::try_from("C1F"),
+                Err(Error::MalformedCode(CodeError::NotLetters(
+                    "C1F".to_string()
+                )))
+            );
+            // Three bytes, but not three letters
+            assert_eq!(
+                CurrencyCode::try_from("Fé"),
+                Err(Error::MalformedCode(CodeError::NotLetters(
+                    "Fé".to_string()
+                )))
+            );
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_from_static_panic() {
+            let _code = CurrencyCode::from_static(b"C1F");
+        }
+
+        #[test]
+        fn test_as_ref() {
+            fn length<S: AsRef<str>>(s: S) -> usize {
+                s.as_ref().len()
+            }
+
+            assert_eq!(CHF.as_ref(), "CHF");
+            assert_eq!(length(CHF), 3);
+        }
+
+        #[test]
+        fn test_borrow() {
+            let mut map = HashMap::new();
+            map.insert(CHF, 1);
+
+            assert_eq!(map.get("CHF"), Some(&1));
+            assert_eq!(map.get("USD"), None);
+        }
+
+        #[cfg(feature = "serialize")]
+        #[test]
+        fn test_deserialize() {
+            use serde::de::{value, Deserialize, IntoDeserializer};
+
+            fn deserialize(code: &[u8]) -> Result<CurrencyCode, value::Error> {
+                CurrencyCode::deserialize(vec![code.to_vec()].into_deserializer())
+            }
+
+            assert_eq!(deserialize(b"CHF"), Ok(CHF));
+            assert!(deserialize(&[b'C', b'H', 0xff]).is_err());
+            assert!(deserialize(b"C1F").is_err());
+        }
+
+        #[test]
+        fn test_error_display() {
+            let invalid = CurrencyCode {
+                code: [b'C', b'H', 0xff],
+            };
+
+            assert_eq!(
+                Error::RateNotFound(CHF).to_string(),
+                "No rate found for currency code \"CHF\"".to_string()
+            );
+            assert_eq!(
+                Error::InvalidRate(invalid).to_string(),
+                "Rate for currency code \"CH\u{fffd}\" is not positive".to_string()
+            );
+        }
+
+        #[test]
+        fn test_try_from_bytes() {
+            assert_eq!(CurrencyCode::try_from(&b"CHF"[..]), Ok(CHF));
+            assert_eq!(
+                CurrencyCode::try_from(&b"CHFX"[..]),
+                Err(Error::MalformedCode(CodeError::WrongLength(
+                    "CHFX".to_string()
+                )))
+            );
+            assert_eq!(
+                CurrencyCode::try_from(&b""[..]),
+                Err(Error::MalformedCode(CodeError::WrongLength(String::new())))
+            );
+            assert_eq!(
+                CurrencyCode::try_from(&[b'C', b'H', 0xff][..]),
+                Err(Error::MalformedCode(CodeError::NotLetters(
+                    "CH\u{fffd}".to_string()
+                )))
+            );
+        }
+
+        #[test]
+        fn test_try_from_trimmed() {
+            assert_eq!(CurrencyCode::try_from_trimmed("  CHF \n"), Ok(CHF));
+            assert_eq!(CurrencyCode::try_from_trimmed("CHF"), Ok(CHF));
+            assert_eq!(
+                CurrencyCode::try_from_trimmed(" C HF "),
+                Err(Error::MalformedCode(CodeError::WrongLength(
+                    "C HF".to_string()
+                )))
+            );
+        }
+
+        #[test]
+        fn test_try_from_numeric() {
+            assert_eq!(CurrencyCode::try_from(756), Ok(CHF));
+            assert_eq!(CurrencyCode::try_from(36), "AUD".try_into());
+            assert_eq!(
+                CurrencyCode::try_from(1),
+                Err(Error::MalformedCode(CodeError::UnknownNumeric(1)))
+            );
+        }
+
+        #[test]
+        fn test_from_static() {
+            assert_eq!(Ok(CHF), "CHF".try_into());
+            assert_eq!(*CHF, *b"CHF");
+        }
+    }
+
+    mod rates {
+        use crate::rates;
+        use crate::{CurrencyAmount, Error, Money, Rates, AMOUNT_UNIT};
+        use std::convert::TryInto;
+
+        #[test]
+        fn test_inverse_worth() {
+            // AMOUNT_UNIT^2 / 1_000_000 and AMOUNT_UNIT^2 / 1_100_000, truncated
+            #[cfg(not(feature = "high-precision"))]
+            let (usd, chf): (i128, i128) = (1_000_000, 909_090);
+            #[cfg(feature = "high-precision")]
+            let (usd, chf): (i128, i128) = (
+                1_000_000_000_000_000_000_000_000_000_000,
+                909_090_909_090_909_090_909_090_909_090,
+            );
+            let rates = rates();
+
+            assert_eq!(
+                rates.inverse_worth("USD".try_into().unwrap()),
+                Ok(CurrencyAmount::from(usd))
+            );
+            assert_eq!(
+                rates.inverse_worth("CHF".try_into().unwrap()),
+                Ok(CurrencyAmount::from(chf))
+            );
+            assert_eq!(
+                rates.inverse_worth("JPY".try_into().unwrap()),
+                Err(Error::RateNotFound("JPY".try_into().unwrap()))
+            );
+
+            let zero = Rates::try_from_iter(vec![("XBT", 0)]).unwrap();
+            assert_eq!(
+                zero.inverse_worth("XBT".try_into().unwrap()),
+                Err(Error::InvalidRate("XBT".try_into().unwrap()))
+            );
+        }
+
+        #[test]
+        fn test_pairs_from() {
+            let rates = rates();
+
+            // The worths are 1.0, 1.1, 1.2 and 1.5 base units, so the results are truncated
+            assert_eq!(
+                rates.pairs_from("USD".try_into().unwrap()),
+                vec![
+                    (
+                        "CHF".try_into().unwrap(),
+                        CurrencyAmount::from(AMOUNT_UNIT * 10 / 11)
+                    ),
+                    (
+                        "EUR".try_into().unwrap(),
+                        CurrencyAmount::from(AMOUNT_UNIT * 10 / 12)
+                    ),
+                    (
+                        "GBP".try_into().unwrap(),
+                        CurrencyAmount::from(AMOUNT_UNIT * 10 / 15)
+                    ),
+                ]
+            );
+            assert_eq!(rates.pairs_from("JPY".try_into().unwrap()), vec![]);
+        }
+
+        #[test]
+        fn test_pairs_from_implicit_base() {
+            let rates = Rates::with_base(
+                vec![("USD".try_into().unwrap(), CurrencyAmount::with_tenths(5))]
+                    .into_iter()
+                    .collect(),
+                "EUR".try_into().unwrap(),
+            );
+
+            assert_eq!(
+                rates.pairs_from("USD".try_into().unwrap()),
+                vec![("EUR".try_into().unwrap(), CurrencyAmount::with_tenths(5))]
+            );
+        }
+
+        #[test]
+        fn test_worth_str() {
+            let rates = rates();
+
+            assert_eq!(
+                rates.worth_str(" usd "),
+                Ok(rates.worth("USD".try_into().unwrap()).unwrap())
+            );
+            assert_eq!(
+                rates.worth_str("cHf"),
+                Ok(rates.worth("CHF".try_into().unwrap()).unwrap())
+            );
+            assert_eq!(
+                rates.worth_str("\tjpy"),
+                Err(Error::RateNotFound("JPY".try_into().unwrap()))
+            );
+            assert!(rates.worth_str(" us d").is_err());
+        }
+
+        #[test]
+        fn test_convert_many() {
+            let rates = rates();
+            let items = vec![
+                Money::with_str_code(1_000_000.into(), "USD").unwrap(),
+                Money::with_str_code(1_000_000.into(), "GBP").unwrap(),
+                Money::with_str_code(2_000_000.into(), "CHF").unwrap(),
+            ];
+
+            assert_eq!(
+                rates.convert_many(&items, "USD".try_into().unwrap()),
+                Ok(vec![
+                    Money::with_str_code(1_000_000.into(), "USD").unwrap(),
+                    Money::with_str_code(1_500_000.into(), "USD").unwrap(),
+                    Money::with_str_code(2_200_000.into(), "USD").unwrap(),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_convert_many_missing_rate() {
+            let rates = rates();
+            let items = vec![
+                Money::with_str_code(CurrencyAmount::with_unit(1), "USD").unwrap(),
+                Money::with_str_code(CurrencyAmount::with_unit(1), "JPY").unwrap(),
+            ];
+
+            assert_eq!(
+                rates.convert_many(&items, "USD".try_into().unwrap()),
+                Err(Error::RateNotFound("JPY".try_into().unwrap()))
+            );
+        }
+
+        #[test]
+        fn test_try_with_rates() {
+            let map = |worths| Rates::try_from_iter(worths).unwrap().into_inner();
+
+            assert!(
+                Rates::try_with_rates(map(vec![("USD", 1_000_000), ("CHF", 1_100_000)])).is_ok()
+            );
+            assert_eq!(
+                Rates::try_with_rates(map(vec![("USD", 1_000_000), ("CHF", 0)])).unwrap_err(),
+                Error::InvalidRate("CHF".try_into().unwrap())
+            );
+            assert_eq!(
+                Rates::try_with_rates(map(vec![("USD", -1_000_000), ("CHF", 1_100_000)]))
+                    .unwrap_err(),
+                Error::InvalidRate("USD".try_into().unwrap())
+            );
+        }
+
+        #[test]
+        fn test_into_inner() {
+            let rates = rates();
+            let map = rates.as_map().clone();
+
+            assert_eq!(map.len(), 4);
+            assert_eq!(rates.clone().into_inner(), map);
+
+            let round_tripped = Rates::with_rates(rates.into_inner());
+
+            assert_eq!(round_tripped.as_map(), &map);
+            assert_eq!(
+                round_tripped.worth("GBP".try_into().unwrap()),
+                Some(CurrencyAmount::from(1_500_000))
+            );
+        }
+
+        #[test]
+        fn test_diff() {
+            let yesterday = rates();
+            let mut map = yesterday.as_map().clone();
+            map.insert("CHF".try_into().unwrap(), 1_050_000.into());
+            map.insert("JPY".try_into().unwrap(), 9_000.into());
+            map.remove("GBP");
+            let today = Rates::with_rates(map);
+
+            assert_eq!(
+                yesterday.diff(&today),
+                vec![
+                    (
+                        "CHF".try_into().unwrap(),
+                        Some(1_100_000.into()),
+                        Some(1_050_000.into())
+                    ),
+                    ("GBP".try_into().unwrap(), Some(1_500_000.into()), None),
+                    ("JPY".try_into().unwrap(), None, Some(9_000.into())),
+                ]
+            );
+            assert!(today.diff(&today).is_empty());
+        }
+
+        #[test]
+        fn test_from_quotes() {
+            let quotes = [("CHF", 0.8), ("EUR", 0.5), ("JPY", 100.0)];
+            let rates = Rates::from_quotes(&quotes, "USD").unwrap();
+
+            assert_eq!(
+                rates.worth("USD".try_into().unwrap()),
+                Some(CurrencyAmount::with_unit(1))
+            );
+            assert_eq!(
+                rates.worth("CHF".try_into().unwrap()),
+                Some(CurrencyAmount::with_cents(125))
+            );
+            assert_eq!(
+                rates.worth("EUR".try_into().unwrap()),
+                Some(CurrencyAmount::with_unit(2))
+            );
+            assert_eq!(
+                rates.worth("JPY".try_into().unwrap()),
+                Some(CurrencyAmount::with_cents(1))
+            );
+        }
+
+        #[test]
+        fn test_from_quotes_invalid() {
+            assert_eq!(
+                Rates::from_quotes(&[("CHF", f64::NAN)], "USD").unwrap_err(),
+                Error::InvalidRate("CHF".try_into().unwrap())
+            );
+            assert_eq!(
+                Rates::from_quotes(&[("CHF", f64::INFINITY)], "USD").unwrap_err(),
+                Error::InvalidRate("CHF".try_into().unwrap())
+            );
+            assert_eq!(
+                Rates::from_quotes(&[("CHF", -0.8)], "USD").unwrap_err(),
+                Error::InvalidRate("CHF".try_into().unwrap())
+            );
+            assert_eq!(
+                Rates::from_quotes(&[("CHF", 1e-300)], "USD").unwrap_err(),
+                Error::InvalidRate("CHF".try_into().unwrap())
+            );
+
+            match Rates::from_quotes(&[("EURO", 0.5)], "USD") {
+                Err(Error::MalformedCode(_)) => (),
+                other => panic!("Expected a malformed code error, got {:?}", other),
+            }
+            match Rates::from_quotes(&[("EUR", 0.5)], "US") {
+                Err(Error::MalformedCode(_)) => (),
+                other => panic!("Expected a malformed code error, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_verify_round_trip() {
+            let rates = rates();
+
+            assert_eq!(rates.verify_round_trip(1.into()), Ok(()));
+            assert!(rates.verify_round_trip(0.into()).is_err());
+        }
+
+        #[test]
+        fn test_verify_round_trip_non_positive_worth() {
+            let rates =
+                Rates::try_from_iter(vec![("CHF", AMOUNT_UNIT), ("EUR", 0), ("USD", AMOUNT_UNIT)])
+                    .unwrap();
+
+            assert_eq!(
+                rates.verify_round_trip(1.into()),
+                Err(vec![
+                    ("CHF".try_into().unwrap(), "EUR".try_into().unwrap()),
+                    ("EUR".try_into().unwrap(), "CHF".try_into().unwrap()),
+                    ("EUR".try_into().unwrap(), "USD".try_into().unwrap()),
+                    ("USD".try_into().unwrap(), "EUR".try_into().unwrap()),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_verify_round_trip_inconsistent() {
+            // A XBT is worth more than AMOUNT_UNIT units of USD, so a unit of USD or CHF is lost
+            // when converted into it
+            let rates = Rates::try_from_iter(vec![
+                ("USD", AMOUNT_UNIT),
+                ("CHF", AMOUNT_UNIT * 11 / 10),
+                ("XBT", AMOUNT_UNIT * AMOUNT_UNIT * 10),
+            ])
+            .unwrap();
+
+            assert_eq!(
+                rates.verify_round_trip(10.into()),
+                Err(vec![
+                    ("CHF".try_into().unwrap(), "XBT".try_into().unwrap()),
+                    ("USD".try_into().unwrap(), "XBT".try_into().unwrap()),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_convert_amount_zero_worth() {
+            // The unchecked constructors accept a zero worth
+            let rates = Rates::try_from_iter(vec![("USD", AMOUNT_UNIT), ("XBT", 0)]).unwrap();
+            let (usd, xbt) = ("USD".try_into().unwrap(), "XBT".try_into().unwrap());
+
+            assert_eq!(
+                rates.convert_amount(CurrencyAmount::with_unit(2), usd, xbt),
+                Err(Error::InvalidRate(xbt))
+            );
+            assert_eq!(
+                rates.clone().with_precision(2).convert_amount(
+                    CurrencyAmount::with_unit(2),
+                    usd,
+                    xbt
+                ),
+                Err(Error::InvalidRate(xbt))
+            );
+            assert_eq!(
+                rates.convert_amount(CurrencyAmount::with_unit(2), xbt, usd),
+                Ok(CurrencyAmount::from(0))
+            );
+        }
+
+        #[test]
+        fn test_convert_amount_overflow() {
+            let rates =
+                Rates::try_from_iter(vec![("USD", AMOUNT_UNIT), ("XBT", i128::MAX)]).unwrap();
+
+            assert_eq!(
+                rates.convert_amount(
+                    CurrencyAmount::with_unit(2),
+                    "XBT".try_into().unwrap(),
+                    "USD".try_into().unwrap()
+                ),
+                Err(Error::Overflow)
+            );
+            assert_eq!(
+                rates.convert_amount(
+                    CurrencyAmount::from(i128::MAX),
+                    "XBT".try_into().unwrap(),
+                    "XBT".try_into().unwrap()
+                ),
+                Ok(CurrencyAmount::from(i128::MAX))
+            );
+        }
+
+        #[test]
+        fn test_mul_div() {
+            use crate::currency::mul_div;
+
+            assert_eq!(mul_div(7, 3, 2), Some(10));
+            assert_eq!(mul_div(-7, 3, 2), Some(-10));
+            assert_eq!(mul_div(7, -3, -2), Some(10));
+            assert_eq!(mul_div(i128::MAX, i128::MAX, i128::MAX), Some(i128::MAX));
+            assert_eq!(mul_div(i128::MAX, 2, 3), Some(i128::MAX / 3 * 2));
+            assert_eq!(
+                mul_div(10i128.pow(30), 10i128.pow(30), 10i128.pow(25)),
+                Some(10i128.pow(35))
+            );
+            assert_eq!(mul_div(i128::MAX, 2, 1), None);
+            assert_eq!(mul_div(1, 1, 0), None);
+        }
+
+        #[test]
+        fn test_collect() {
+            let rates: Rates = vec![("USD", 1_000_000), ("CHF", 1_100_000)]
+                .into_iter()
+                .map(|(code, worth)| (code.try_into().unwrap(), CurrencyAmount::from(worth)))
+                .collect();
+
+            assert_eq!(
+                rates.worth("USD".try_into().unwrap()),
+                Some(CurrencyAmount::from(1_000_000))
+            );
+            assert_eq!(
+                rates.worth("CHF".try_into().unwrap()),
+                Some(CurrencyAmount::from(1_100_000))
+            );
+        }
+
+        #[test]
+        fn test_try_from_iter() {
+            let rates = Rates::try_from_iter(vec![("USD", 1_000_000), ("CHF", 1_100_000)]).unwrap();
+
+            assert_eq!(
+                rates.worth("CHF".try_into().unwrap()),
+                Some(CurrencyAmount::from(1_100_000))
+            );
+            assert!(Rates::try_from_iter(vec![("USD", 1_000_000), ("CHFX", 1_100_000)]).is_err());
+        }
+
+        #[test]
+        fn test_with_base() {
+            let map = vec![("CHF".try_into().unwrap(), CurrencyAmount::with_tenths(11))]
+                .into_iter()
+                .collect();
+            let rates = Rates::with_base(map, "USD".try_into().unwrap());
+
+            assert_eq!(
+                rates.worth("USD".try_into().unwrap()),
+                Some(CurrencyAmount::with_unit(1))
+            );
+            assert_eq!(
+                rates.convert_amount(
+                    CurrencyAmount::with_unit(1),
+                    "CHF".try_into().unwrap(),
+                    "USD".try_into().unwrap()
+                ),
+                Ok(CurrencyAmount::with_tenths(11))
+            );
+        }
+
+        #[test]
+        fn test_with_base_explicit_entry_wins() {
+            let map = vec![("USD", 2_000_000)]
+                .into_iter()
+                .map(|(code, worth)| (code.try_into().unwrap(), worth.into()))
+                .collect();
+            let rates = Rates::with_base(map, "USD".try_into().unwrap());
+
+            assert_eq!(
+                rates.worth("USD".try_into().unwrap()),
+                Some(CurrencyAmount::from(2_000_000))
+            );
+        }
+
+        #[test]
+        fn test_convert_amount() {
+            let rates = rates();
+
+            // 1 USD = 1_000_000 base, 1 GBP = 1_500_000 base => 1 USD = 2/3 GBP
+            assert_eq!(
+                rates.convert_amount(
+                    1_000_000.into(),
+                    "USD".try_into().unwrap(),
+                    "GBP".try_into().unwrap()
+                ),
+                Ok(CurrencyAmount::from(666_666))
+            );
+        }
+
+        #[test]
+        fn test_with_precision() {
+            // A CHF is worth slightly less than 2 USD
+            let map = vec![("USD", 1_000_000), ("CHF", 1_999_999)]
+                .into_iter()
+                .map(|(code, worth)| (code.try_into().unwrap(), worth.into()))
+                .collect();
+            let rates = Rates::with_rates(map);
+            let precise_rates = rates.clone().with_precision(2);
+            let convert = |rates: &Rates, amount: i128| {
+                rates
+                    .convert_amount(
+                        amount.into(),
+                        "CHF".try_into().unwrap(),
+                        "USD".try_into().unwrap(),
+                    )
+                    .unwrap()
+            };
+
+            // Exact results are 1.999999 and -1.999999
+            assert_eq!(convert(&rates, 1), CurrencyAmount::from(1));
+            assert_eq!(convert(&precise_rates, 1), CurrencyAmount::from(2));
+            assert_eq!(convert(&rates, -1), CurrencyAmount::from(-1));
+            assert_eq!(convert(&precise_rates, -1), CurrencyAmount::from(-2));
+            // Exact result is 9.999995
+            assert_eq!(convert(&rates, 5), CurrencyAmount::from(9));
+            assert_eq!(convert(&precise_rates, 5), CurrencyAmount::from(10));
+        }
+
+        #[test]
+        fn test_with_precision_overflow() {
+            let rates = rates();
+            let convert = |extra_digits: u8| {
+                rates.clone().with_precision(extra_digits).convert_amount(
+                    CurrencyAmount::with_unit(1),
+                    "CHF".try_into().unwrap(),
+                    "USD".try_into().unwrap(),
+                )
+            };
+
+            assert_eq!(convert(3), Ok(CurrencyAmount::with_tenths(11)));
+            assert_eq!(convert(39), Err(Error::Overflow));
+            assert_eq!(convert(40), Err(Error::Overflow));
+            assert_eq!(convert(u8::MAX), Err(Error::Overflow));
+        }
+
+        #[test]
+        fn test_convert_amount_missing_rate() {
+            let rates = rates();
+
+            assert_eq!(
+                rates.convert_amount(
+                    CurrencyAmount::with_unit(1),
+                    "JPY".try_into().unwrap(),
+                    "GBP".try_into().unwrap()
+                ),
+                Err(Error::RateNotFound("JPY".try_into().unwrap()))
+            );
+            assert_eq!(
+                rates.convert_amount(
+                    CurrencyAmount::with_unit(1),
+                    "USD".try_into().unwrap(),
+                    "JPY".try_into().unwrap()
+                ),
+                Err(Error::RateNotFound("JPY".try_into().unwrap()))
+            );
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{CurrencyAmount, CurrencyCode, Error, Rates, AMOUNT_UNIT};
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+impl Rates {
+    /// Parse the daily reference rates published by the European Central Bank
+    /// (`eurofxref-daily.xml`) into EUR-based `Rates`.
+    ///
+    /// The ECB publishes how many units of a currency make one EUR, so the worth of each
+    /// currency is computed as `AMOUNT_UNIT^2 / rate` (see
+    /// [`inverse_worth`](struct.Rates.html#method.inverse_worth) for the precision implications).
+    ///
+    /// Returns `Error::MalformedCode` for an invalid currency code and `Error::InvalidRate` for a
+    /// rate that is not a positive decimal number.
+    pub fn from_ecb_xml(xml: &str) -> crate::Result<Rates> {
+        let mut map = HashMap::new();
+
+        for tag in xml.split("<Cube").skip(1) {
+            let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+
+            if let (Some(currency), Some(rate)) =
+                (attribute(tag, "currency"), attribute(tag, "rate"))
+            {
+                let code: CurrencyCode = currency.try_into()?;
+                let rate = parse_rate(rate)
+                    .filter(|rate| **rate > 0)
+                    .ok_or(Error::InvalidRate(code))?;
+
+                map.insert(code, CurrencyAmount::from(AMOUNT_UNIT * AMOUNT_UNIT) / rate);
+            }
+        }
+
+        Ok(Rates::with_base(map, CurrencyCode::from_static(b"EUR")))
+    }
+}
+
+/// Get the value of the attribute `name` in `tag`, preceded by any whitespace and quoted with
+/// either `'` or `"`.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!("{}=", name);
+    let start = tag
+        .match_indices(&pattern)
+        .map(|(index, _)| index)
+        .find(|&index| tag[..index].ends_with(char::is_whitespace))?
+        + pattern.len();
+    let quote = tag[start..]
+        .chars()
+        .next()
+        .filter(|c| *c == '\'' || *c == '"')?;
+    let value = &tag[start + 1..];
+
+    Some(&value[..value.find(quote)?])
+}
+
+/// Parse a decimal number such as `1.0850` into a `CurrencyAmount`.
+/// Decimals beyond the precision of `AMOUNT_UNIT` are truncated.
+fn parse_rate(rate: &str) -> Option<CurrencyAmount> {
+    let mut parts = rate.splitn(2, '.');
+    let units = parts.next()?;
+
+    if units.is_empty() || !units.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut amount = units.parse::<i128>().ok()?.checked_mul(AMOUNT_UNIT)?;
+    let mut scale = AMOUNT_UNIT;
+
+    for digit in parts.next().unwrap_or("").chars() {
+        scale /= 10;
+        amount += i128::from(digit.to_digit(10)?) * scale;
+    }
+
+    Some(amount.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CurrencyAmount, Error, Rates};
+    use std::convert::TryInto;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+	<gesmes:subject>Reference rates</gesmes:subject>
+	<gesmes:Sender>
+		<gesmes:name>European Central Bank</gesmes:name>
+	</gesmes:Sender>
+	<Cube>
+		<Cube time='2019-11-08'>
+			<Cube currency='USD' rate='1.1020'/>
+			<Cube currency='JPY' rate='120.44'/>
+			<Cube currency='GBP' rate='0.86103'/>
+			<Cube currency='CHF' rate='1.0995'/>
+		</Cube>
+	</Cube>
+</gesmes:Envelope>"#;
+
+    #[test]
+    fn test_from_ecb_xml() {
+        let rates = Rates::from_ecb_xml(SAMPLE).unwrap();
+
+        assert_eq!(
+            rates.worth("EUR".try_into().unwrap()),
+            Some(CurrencyAmount::with_unit(1))
+        );
+        assert!(rates.worth("CHF".try_into().unwrap()).is_some());
+        assert_eq!(rates.worth("SEK".try_into().unwrap()), None);
+    }
+
+    #[test]
+    fn test_from_ecb_xml_worths() {
+        // 1 / 1.1020, 1 / 120.44 and 1 / 0.86103, truncated
+        #[cfg(not(feature = "high-precision"))]
+        let (usd, jpy, gbp): (i128, i128, i128) = (907_441, 8_302, 1_161_399);
+        #[cfg(feature = "high-precision")]
+        let (usd, jpy, gbp): (i128, i128, i128) = (
+            907_441_016_333_938_294,
+            8_302_889_405_513_118,
+            1_161_399_718_941_268_016,
+        );
+        let rates = Rates::from_ecb_xml(SAMPLE).unwrap();
+
+        assert_eq!(
+            rates.worth("USD".try_into().unwrap()),
+            Some(CurrencyAmount::from(usd))
+        );
+        assert_eq!(
+            rates.worth("JPY".try_into().unwrap()),
+            Some(CurrencyAmount::from(jpy))
+        );
+        assert_eq!(
+            rates.worth("GBP".try_into().unwrap()),
+            Some(CurrencyAmount::from(gbp))
+        );
+    }
+
+    #[test]
+    fn test_from_ecb_xml_multi_line() {
+        let xml = r#"<Cube>
+	<Cube
+		currency="USD"
+		rate="1.1020"/>
+	<Cube	currency='CHF'
+		rate='1.0995'/>
+</Cube>"#;
+        let rates = Rates::from_ecb_xml(xml).unwrap();
+
+        assert_eq!(
+            rates.worth("USD".try_into().unwrap()),
+            Rates::from_ecb_xml(SAMPLE)
+                .unwrap()
+                .worth("USD".try_into().unwrap())
+        );
+        assert!(rates.worth("CHF".try_into().unwrap()).is_some());
+    }
+
+    #[test]
+    fn test_from_ecb_xml_invalid_rate() {
+        let xml = "<Cube><Cube time='2019-11-08'><Cube currency='USD' rate='abc'/></Cube></Cube>";
+
+        assert_eq!(
+            Rates::from_ecb_xml(xml).unwrap_err(),
+            Error::InvalidRate("USD".try_into().unwrap())
+        );
+
+        let xml = "<Cube><Cube time='2019-11-08'><Cube currency='USD' rate='0.0'/></Cube></Cube>";
+
+        assert_eq!(
+            Rates::from_ecb_xml(xml).unwrap_err(),
+            Error::InvalidRate("USD".try_into().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_from_ecb_xml_malformed_code() {
+        let xml = "<Cube><Cube time='2019-11-08'><Cube currency='USDX' rate='1.1'/></Cube></Cube>";
+
+        match Rates::from_ecb_xml(xml) {
+            Err(Error::MalformedCode(_)) => (),
+            other => panic!("Expected a malformed code error, got {:?}", other),
+        }
+    }
+}
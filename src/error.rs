@@ -0,0 +1,69 @@
+use crate::CurrencyCode;
+use std::fmt;
+
+/// Errors that can occurr in this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// No rate is defined for the given currency code.
+    RateNotFound(CurrencyCode),
+    /// The rate of the given currency code is not strictly positive.
+    InvalidRate(CurrencyCode),
+    /// The given value is not a valid currency code.
+    MalformedCode(CodeError),
+    /// A conversion doesn't fit in a `CurrencyAmount`.
+    Overflow,
+}
+
+/// The reason why a currency code is malformed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeError {
+    /// The given string has not length 3.
+    WrongLength(String),
+    /// The given string contains something else than ASCII letters.
+    NotLetters(String),
+    /// No ISO 4217 currency has the given numeric code.
+    UnknownNumeric(u16),
+    /// No ISO 4217 currency has the given alphabetic code.
+    UnknownAlpha(String),
+}
+
+/// Shorthand for a `Result` having this crate's [`Error`](enum.Error.html).
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::RateNotFound(code) => write!(
+                f,
+                "No rate found for currency code \"{}\"",
+                String::from_utf8_lossy(&code[..])
+            ),
+            Error::InvalidRate(code) => write!(
+                f,
+                "Rate for currency code \"{}\" is not positive",
+                String::from_utf8_lossy(&code[..])
+            ),
+            Error::MalformedCode(reason) => write!(f, "Malformed currency code: {}", reason),
+            Error::Overflow => write!(f, "Conversion overflowed"),
+        }
+    }
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodeError::WrongLength(code) => write!(f, "\"{}\" has not length 3", code),
+            CodeError::NotLetters(code) => {
+                write!(f, "\"{}\" contains something else than letters", code)
+            }
+            CodeError::UnknownNumeric(numeric) => {
+                write!(f, "no ISO 4217 currency has numeric code {:03}", numeric)
+            }
+            CodeError::UnknownAlpha(code) => {
+                write!(f, "no ISO 4217 currency has code \"{}\"", code)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,123 @@
+use crate::{CurrencyCode, Money, Rates};
+use std::collections::BTreeMap;
+
+/// Snapshots of `Rates` by date, to convert money as of a specific date.
+///
+/// The date can be any ordered type, such as `chrono::NaiveDate`, a `(year, month, day)` tuple
+/// or a timestamp. A date without its own snapshot uses the latest snapshot before it, so that
+/// for example weekends use the rates of the previous Friday.
+#[derive(Debug, Clone)]
+pub struct RateHistory<D> {
+    snapshots: BTreeMap<D, Rates>,
+}
+
+impl<D: Ord> RateHistory<D> {
+    /// Creates an empty history.
+    pub fn new() -> Self {
+        RateHistory {
+            snapshots: BTreeMap::new(),
+        }
+    }
+
+    /// Records the rates valid from `date` on. Returns the rates previously recorded for
+    /// exactly that date, if any.
+    pub fn insert(&mut self, date: D, rates: Rates) -> Option<Rates> {
+        self.snapshots.insert(date, rates)
+    }
+
+    /// The rates valid on `date`: the latest snapshot at or before it.
+    /// Returns `None` if `date` is before the first snapshot.
+    pub fn on(&self, date: &D) -> Option<&Rates> {
+        self.snapshots
+            .range(..=date)
+            .next_back()
+            .map(|(_, rates)| rates)
+    }
+
+    /// Converts `money` into the currency `code` with the rates valid on `date`.
+    /// Returns `None` if there are no rates for `date` or if either code has no rate then.
+    /// See [`Money::into_code`](struct.Money.html#method.into_code).
+    pub fn convert_on(&self, date: &D, money: Money, code: CurrencyCode) -> Option<Money> {
+        money.into_code(code, self.on(date)?)
+    }
+}
+
+impl<D: Ord> Default for RateHistory<D> {
+    fn default() -> Self {
+        RateHistory::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CurrencyAmount, Money, RateHistory, Rates};
+    use std::convert::TryInto;
+
+    fn history() -> RateHistory<(u16, u8, u8)> {
+        let mut history = RateHistory::new();
+
+        history.insert(
+            (2020, 1, 1),
+            Rates::with_rates(
+                vec![("USD", 1_000_000), ("CHF", 1_000_000)]
+                    .into_iter()
+                    .map(|(code, worth)| (code.try_into().unwrap(), worth.into()))
+                    .collect(),
+            ),
+        );
+        history.insert(
+            (2020, 6, 1),
+            Rates::with_rates(
+                vec![("USD", 1_000_000), ("CHF", 2_000_000)]
+                    .into_iter()
+                    .map(|(code, worth)| (code.try_into().unwrap(), worth.into()))
+                    .collect(),
+            ),
+        );
+
+        history
+    }
+
+    #[test]
+    fn test_on() {
+        let history = history();
+        let chf = "CHF".try_into().unwrap();
+
+        assert!(history.on(&(2019, 12, 31)).is_none());
+        assert_eq!(
+            history.on(&(2020, 1, 1)).and_then(|rates| rates.worth(chf)),
+            Some(1_000_000.into())
+        );
+        assert_eq!(
+            history
+                .on(&(2020, 5, 31))
+                .and_then(|rates| rates.worth(chf)),
+            Some(1_000_000.into())
+        );
+        assert_eq!(
+            history.on(&(2021, 1, 1)).and_then(|rates| rates.worth(chf)),
+            Some(2_000_000.into())
+        );
+    }
+
+    #[test]
+    fn test_convert_on() {
+        let history = history();
+        let money = Money::with_str_code(CurrencyAmount::with_unit(10), "CHF").unwrap();
+        let usd = "USD".try_into().unwrap();
+
+        assert_eq!(
+            history.convert_on(&(2020, 3, 1), money, usd),
+            Money::with_str_code(CurrencyAmount::with_unit(10), "USD")
+        );
+        assert_eq!(
+            history.convert_on(&(2020, 6, 1), money, usd),
+            Money::with_str_code(CurrencyAmount::with_unit(20), "USD")
+        );
+        assert_eq!(history.convert_on(&(2019, 1, 1), money, usd), None);
+        assert_eq!(
+            history.convert_on(&(2020, 6, 1), money, "JPY".try_into().unwrap()),
+            None
+        );
+    }
+}
@@ -1,24 +1,60 @@
 #![deny(warnings)]
 
 mod currency;
+#[cfg(feature = "ecb")]
+mod ecb;
+mod error;
+mod history;
 mod ops;
+mod spread;
+#[cfg(feature = "diesel")]
+mod sql;
+#[cfg(feature = "words")]
+mod words;
 
 pub use currency::{CurrencyCode, Exponent, Rates};
+pub use error::{CodeError, Error, Result};
+pub use history::RateHistory;
 pub use ops::Operation;
+pub use spread::{Side, SpreadRates};
 
-use std::convert::TryInto;
+use std::cmp::Ordering;
+use std::convert::{TryFrom, TryInto};
 use std::fmt;
 
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
 /// How much `amount` makes a unit
+#[cfg(not(feature = "high-precision"))]
 pub const AMOUNT_UNIT: i128 = 1_000_000;
 
+/// How much `amount` makes a unit.
+/// The `high-precision` feature allows up to 18 decimals, which is needed by some crypto tokens.
+/// Conversions between currencies use a wider intermediate product and return
+/// `Error::Overflow` only if the result doesn't fit, but beware that multiplying such amounts
+/// directly overflows much sooner.
+#[cfg(feature = "high-precision")]
+pub const AMOUNT_UNIT: i128 = 1_000_000_000_000_000_000;
+
+/// The precision used to display money whose currency code is not ISO 4217, such as a custom
+/// currency added to `Rates`. It applies to `Display`, `Money::amount_string`,
+/// `Money::fraction`, `Money::format_with_name` and `Money::to_words`.
+pub const FALLBACK_PRECISION: usize = 2;
+
 /// Holds an amount of currency. The `i128` it holds is
 /// expressed in fractions of a unit.
 /// `CurrencyAmount(`[`AMOUNT_UNIT`](constant.AMOUNT_UNIT.html)`)` makes a unit.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+///
+/// It can be created from any of `i128`, `i64`, `i32` and `u32`.
+///
+/// # Breaking change in 0.2
+///
+/// With several `From` implementations, an unsuffixed integer literal passed to `.into()` is
+/// inferred as `i32` instead of `i128`, so literals beyond the `i32` range need a suffix:
+/// `5_000_000_000i128.into()`. Keeping only `From<i64>` besides `From<i128>` would break them
+/// just the same.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub struct CurrencyAmount(i128);
 
@@ -54,6 +90,20 @@ impl CurrencyAmount {
     pub fn into_thousands(self) -> Self {
         CurrencyAmount(self.0 * 1000 / AMOUNT_UNIT)
     }
+
+    /// Splits this amount into its whole units and its fractional part scaled to `decimals`
+    /// places, both having the sign of the amount: `with_cents(2125).split(2)` is `(21, 25)`.
+    /// Digits beyond `decimals` are truncated.
+    ///
+    /// # Panics
+    ///
+    /// If `decimals` exceeds the precision of [`AMOUNT_UNIT`](constant.AMOUNT_UNIT.html).
+    pub fn split(self, decimals: u8) -> (i128, i128) {
+        (
+            self.0 / AMOUNT_UNIT,
+            self.0 % AMOUNT_UNIT / (AMOUNT_UNIT / 10i128.pow(u32::from(decimals))),
+        )
+    }
 }
 
 impl std::ops::Deref for CurrencyAmount {
@@ -95,12 +145,38 @@ impl std::ops::Div for CurrencyAmount {
     }
 }
 
+impl std::ops::Neg for CurrencyAmount {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        CurrencyAmount(-self.0)
+    }
+}
+
 impl From<i128> for CurrencyAmount {
     fn from(i: i128) -> Self {
         CurrencyAmount(i)
     }
 }
 
+impl From<i64> for CurrencyAmount {
+    fn from(i: i64) -> Self {
+        CurrencyAmount(i128::from(i))
+    }
+}
+
+impl From<i32> for CurrencyAmount {
+    fn from(i: i32) -> Self {
+        CurrencyAmount(i128::from(i))
+    }
+}
+
+impl From<u32> for CurrencyAmount {
+    fn from(i: u32) -> Self {
+        CurrencyAmount(i128::from(i))
+    }
+}
+
 impl From<CurrencyAmount> for i128 {
     fn from(amount: CurrencyAmount) -> Self {
         *amount
@@ -146,32 +222,142 @@ impl Money {
         }
     }
 
+    /// Converts this money into another currency code. Returns `None` if either code has no rate
+    /// or if the conversion overflows.
+    /// See [`Rates::convert_amount`](struct.Rates.html#method.convert_amount).
     pub fn into_code(self, code: CurrencyCode, rates: &Rates) -> Option<Money> {
-        let worth_self = rates.worth(self.currency_code)?;
-        let worth_new = rates.worth(code)?;
+        let amount = rates
+            .convert_amount(self.amount, self.currency_code, code)
+            .ok()?;
 
         Some(Money {
-            amount: self.amount * worth_self / worth_new,
+            amount,
             currency_code: code,
         })
     }
 
-    /// Creates `Money` with given amount and code. Returns `None` if the given code is not three characters long.
+    /// Compares this money with `other` by economic worth, converting `other` into the
+    /// currency of this money. Returns `Error::RateNotFound` if either currency has no rate.
+    pub fn cmp_in(&self, other: &Money, rates: &Rates) -> Result<Ordering> {
+        let other_amount =
+            rates.convert_amount(other.amount, other.currency_code, self.currency_code)?;
+
+        Ok(self.amount.cmp(&other_amount))
+    }
+
+    /// Whether this money is worth more than `other`. See [`cmp_in`](#method.cmp_in).
+    pub fn gt_in(&self, other: &Money, rates: &Rates) -> Result<bool> {
+        Ok(self.cmp_in(other, rates)? == Ordering::Greater)
+    }
+
+    /// Whether this money is worth less than `other`. See [`cmp_in`](#method.cmp_in).
+    pub fn lt_in(&self, other: &Money, rates: &Rates) -> Result<bool> {
+        Ok(self.cmp_in(other, rates)? == Ordering::Less)
+    }
+
+    /// Creates `Money` with given amount and code. Returns `None` if the given code is not three ASCII letters.
     pub fn with_str_code(amount: CurrencyAmount, currency_code: &str) -> Option<Money> {
         Some(Money::new(amount, currency_code.try_into().ok()?))
     }
+
+    /// Creates `Money` with given amount and code, like [`with_str_code`](#method.with_str_code),
+    /// but also checks that the code is an ISO 4217 currency, so that it can be displayed.
+    /// Codes are case sensitive: `"chf"` is not known.
+    pub fn with_iso_code(amount: CurrencyAmount, currency_code: &str) -> Result<Money> {
+        let code: CurrencyCode = currency_code.try_into()?;
+
+        if iso4217::alpha3(currency_code).is_none() {
+            return Err(Error::MalformedCode(CodeError::UnknownAlpha(
+                currency_code.to_string(),
+            )));
+        }
+
+        Ok(Money::new(amount, code))
+    }
+
+    /// The whole units of this money: `12` for `12.10 CHF`.
+    pub fn units(&self) -> i128 {
+        self.amount.split(0).0
+    }
+
+    /// The part of this money below one whole unit, scaled to the precision of the currency
+    /// code (see ISO 4217 exponent): `10` for `12.10 CHF`.
+    /// It has the same sign as the amount.
+    pub fn fraction(&self) -> i128 {
+        self.amount.split(self.default_precision() as u8).1
+    }
+
+    /// Renders only the amount, without the currency code, at the precision of the
+    /// currency code (see ISO 4217 exponent). `12.10 CHF` is rendered as `12.10`.
+    pub fn amount_string(&self) -> String {
+        let mut amount = String::new();
+        self.write_amount(&mut amount, self.default_precision())
+            .expect("ISO 4217 exponents fit in AMOUNT_UNIT");
+        amount
+    }
+
+    /// Renders the amount followed by the ISO 4217 name of the currency instead of its code:
+    /// `1.00 USD` is rendered as `1.00 United States dollar`.
+    /// Codes that are not ISO 4217 have no name and are rendered as the code: `1.00 XYZ`.
+    pub fn format_with_name(&self) -> String {
+        let code = self.currency_code.as_ref();
+        let name = iso4217::alpha3(code).map_or(code, |currency| currency.name);
+
+        format!("{} {}", self.amount_string(), name)
+    }
+
+    /// The precision of the currency code (see ISO 4217 exponent), or
+    /// [`FALLBACK_PRECISION`](constant.FALLBACK_PRECISION.html) for codes that are not ISO 4217.
+    fn default_precision(&self) -> usize {
+        iso4217::alpha3(self.currency_code.as_ref())
+            .map(|currency| currency.exp as usize)
+            .unwrap_or(FALLBACK_PRECISION)
+    }
+
+    /// Writes the amount with the given `precision`. Fails if `precision` exceeds the precision
+    /// of [`AMOUNT_UNIT`](constant.AMOUNT_UNIT.html).
+    fn write_amount<W: fmt::Write>(&self, w: &mut W, precision: usize) -> fmt::Result {
+        let (units, decimals) = u8::try_from(precision)
+            .ok()
+            .filter(|decimals| {
+                10i128
+                    .checked_pow(u32::from(*decimals))
+                    .filter(|scale| *scale <= AMOUNT_UNIT)
+                    .is_some()
+            })
+            .map(|decimals| self.amount.split(decimals))
+            .ok_or(fmt::Error)?;
+
+        if *self.amount < 0 {
+            w.write_str("-")?;
+        }
+
+        if precision > 0 {
+            write!(
+                w,
+                "{units}.{decimals:0precision$}",
+                units = units.unsigned_abs(),
+                decimals = decimals.unsigned_abs(),
+                precision = precision,
+            )
+        } else {
+            write!(w, "{units}", units = units.unsigned_abs())
+        }
+    }
 }
 
 /// Money can be displayed in the following format: `12.10 CHF`.
 ///
 /// Default precision is dependent on the currency code (see ISO 4217 exponent).
-/// A custom precision in range `0..=6` can be provided like this:
+/// Codes that are not ISO 4217 use [`FALLBACK_PRECISION`](constant.FALLBACK_PRECISION.html).
+/// A custom precision in range `0..=6` (`0..=18` with the `high-precision` feature)
+/// can be provided like this:
 ///
 /// ```
 ///
-/// use monet::Money;
+/// use monet::{CurrencyAmount, Money};
 ///
-/// let money = Money::with_str_code(12_100_000.into(), "CHF").unwrap();
+/// let money = Money::with_str_code(CurrencyAmount::with_cents(1210), "CHF").unwrap();
 ///
 /// assert_eq!(
 ///     &format!("{}", money),
@@ -196,30 +382,52 @@ impl Money {
 /// );
 ///
 /// ```
+///
+/// The alternate flag (`{:#}`) displays the currency symbol instead of the code,
+/// falling back to the code if no symbol is known:
+///
+/// ```
+///
+/// use monet::{CurrencyAmount, Money};
+///
+/// let money = Money::with_str_code(CurrencyAmount::with_cents(1210), "CHF").unwrap();
+///
+/// assert_eq!(
+///     &format!("{:#}", money),
+///     "12.10 Fr."
+/// );
+///
+/// ```
+///
+/// The sign flag (`{:+}`) prefixes non-negative amounts with a `+`:
+///
+/// ```
+///
+/// use monet::{CurrencyAmount, Money};
+///
+/// let money = Money::with_str_code(CurrencyAmount::with_cents(1210), "CHF").unwrap();
+///
+/// assert_eq!(
+///     &format!("{:+}", money),
+///     "+12.10 CHF"
+/// );
+///
+/// ```
 impl fmt::Display for Money {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use iso4217::alpha3;
-
-        let code: &str = (&self.currency_code).try_into().unwrap();
-        let units = *self.amount / AMOUNT_UNIT;
-        let decimals = *self.amount % AMOUNT_UNIT;
-        let precision = f
-            .precision()
-            .unwrap_or_else(|| alpha3(code).unwrap().exp as usize);
-
-        if precision > 0 {
-            write!(
-                f,
-                "{units}.{decimals} {code}",
-                units = units,
-                decimals = decimals
-                    .checked_div(AMOUNT_UNIT / 10i128.pow(precision as u32))
-                    .ok_or(fmt::Error)?,
-                code = code
-            )
+        let code = self.currency_code.as_ref();
+        let code_or_symbol = if f.alternate() {
+            currency::symbol(code).unwrap_or(code)
         } else {
-            write!(f, "{units} {code}", units = units, code = code)
+            code
+        };
+        let precision = f.precision().unwrap_or_else(|| self.default_precision());
+
+        if f.sign_plus() && *self.amount >= 0 {
+            f.write_str("+")?;
         }
+        self.write_amount(f, precision)?;
+        write!(f, " {code}", code = code_or_symbol)
     }
 }
 
@@ -260,14 +468,52 @@ fn rates() -> Rates {
 #[cfg(test)]
 mod tests {
 
+    mod currency_amount {
+        use crate::CurrencyAmount;
+
+        #[test]
+        fn test_split() {
+            assert_eq!(CurrencyAmount::with_cents(2125).split(2), (21, 25));
+            assert_eq!(CurrencyAmount::with_cents(2125).split(1), (21, 2));
+            assert_eq!(CurrencyAmount::with_cents(2125).split(3), (21, 250));
+            assert_eq!(CurrencyAmount::with_cents(2125).split(0), (21, 0));
+            assert_eq!(CurrencyAmount::with_cents(-2125).split(2), (-21, -25));
+            assert_eq!(CurrencyAmount::with_cents(-5).split(2), (0, -5));
+        }
+
+        #[test]
+        fn test_neg() {
+            assert_eq!(
+                -CurrencyAmount::with_cents(2125),
+                CurrencyAmount::with_cents(-2125)
+            );
+            assert_eq!(-CurrencyAmount::from(0), CurrencyAmount::from(0));
+        }
+
+        #[test]
+        fn test_from_integers() {
+            assert_eq!(CurrencyAmount::from(-5i128), CurrencyAmount(-5));
+            assert_eq!(
+                CurrencyAmount::from(i64::MIN),
+                CurrencyAmount(i128::from(i64::MIN))
+            );
+            assert_eq!(CurrencyAmount::from(-5i32), CurrencyAmount(-5));
+            assert_eq!(
+                CurrencyAmount::from(u32::MAX),
+                CurrencyAmount(i128::from(u32::MAX))
+            );
+        }
+    }
+
     mod money {
         use crate::rates;
         use crate::CurrencyAmount;
-        use crate::Money;
+        use crate::{CodeError, Error, Money, Rates, AMOUNT_UNIT};
+        use std::cmp::Ordering;
         use std::convert::TryInto;
 
         #[cfg(feature = "serialize")]
-        use serde::{Serialize, Deserialize};
+        use serde::{Deserialize, Serialize};
 
         #[test]
         fn test_into_code() {
@@ -306,11 +552,223 @@ mod tests {
             assert_eq!(format!("{:.0}", money), "21 CHF".to_string());
         }
 
+        #[test]
+        fn test_display_pads_decimals() {
+            let money = Money::with_str_code(CurrencyAmount::with_cents(1205), "CHF").unwrap();
+
+            assert_eq!(format!("{}", money), "12.05 CHF".to_string());
+            assert_eq!(format!("{:.4}", money), "12.0500 CHF".to_string());
+        }
+
+        #[test]
+        fn test_with_iso_code() {
+            let amount = CurrencyAmount::with_unit(1);
+
+            assert_eq!(
+                Money::with_iso_code(amount, "CHF"),
+                Ok(Money::new(amount, "CHF".try_into().unwrap()))
+            );
+            assert_eq!(
+                Money::with_iso_code(amount, "XYZ"),
+                Err(Error::MalformedCode(CodeError::UnknownAlpha(
+                    "XYZ".to_string()
+                )))
+            );
+            assert_eq!(
+                Money::with_iso_code(amount, "chf"),
+                Err(Error::MalformedCode(CodeError::UnknownAlpha(
+                    "chf".to_string()
+                )))
+            );
+            assert!(Money::with_iso_code(amount, "USDX").is_err());
+            assert!(Money::with_str_code(amount, "XYZ").is_some());
+        }
+
+        #[test]
+        fn test_display_unknown_code() {
+            let money = Money::with_str_code(CurrencyAmount::with_cents(2125), "XYZ").unwrap();
+
+            assert_eq!(format!("{}", money), "21.25 XYZ".to_string());
+            assert_eq!(format!("{:.3}", money), "21.250 XYZ".to_string());
+            assert_eq!(format!("{:#}", money), "21.25 XYZ".to_string());
+            assert_eq!(money.amount_string(), "21.25".to_string());
+            assert_eq!(money.fraction(), 25);
+        }
+
+        #[test]
+        fn test_display_sign() {
+            let positive = Money::with_str_code(CurrencyAmount::with_unit(12), "USD").unwrap();
+            let negative = Money::with_str_code(CurrencyAmount::with_cents(-1250), "USD").unwrap();
+            let zero = Money::with_str_code(CurrencyAmount::from(0), "USD").unwrap();
+
+            assert_eq!(format!("{:+}", positive), "+12.00 USD".to_string());
+            assert_eq!(format!("{:+}", negative), "-12.50 USD".to_string());
+            assert_eq!(format!("{:+}", zero), "+0.00 USD".to_string());
+            assert_eq!(format!("{:+.0}", positive), "+12 USD".to_string());
+            assert_eq!(format!("{}", positive), "12.00 USD".to_string());
+            assert_eq!(format!("{}", negative), "-12.50 USD".to_string());
+        }
+
+        #[cfg(feature = "high-precision")]
+        #[test]
+        fn test_display_high_precision() {
+            let money = Money::with_str_code(1_000_000_000_000_000_001i128.into(), "ETH").unwrap();
+
+            assert_eq!(
+                format!("{:.18}", money),
+                "1.000000000000000001 ETH".to_string()
+            );
+            assert_eq!(money.amount.into_unit(), CurrencyAmount::from(1));
+        }
+
+        #[test]
+        fn test_into_code_big_amount() {
+            // 1 ETH is worth 2000 USD, the base currency
+            let rates = Rates::with_base(
+                vec![("ETH".try_into().unwrap(), CurrencyAmount::with_unit(2000))]
+                    .into_iter()
+                    .collect(),
+                "USD".try_into().unwrap(),
+            );
+            let money = Money::with_str_code(
+                CurrencyAmount::with_unit(1000) + CurrencyAmount::from(1),
+                "ETH",
+            )
+            .unwrap();
+
+            assert_eq!(
+                money.into_code("USD".try_into().unwrap(), &rates),
+                Money::with_str_code(
+                    CurrencyAmount::with_unit(2_000_000) + CurrencyAmount::from(2000),
+                    "USD"
+                )
+            );
+        }
+
+        #[cfg(feature = "high-precision")]
+        #[test]
+        fn test_into_code_high_precision() {
+            // 1 ETH is worth 1.5 USD, the base currency
+            let rates = Rates::with_base(
+                vec![("ETH".try_into().unwrap(), CurrencyAmount::with_tenths(15))]
+                    .into_iter()
+                    .collect(),
+                "USD".try_into().unwrap(),
+            );
+            let money = Money::with_str_code(1_000_000_000_000_000_001i128.into(), "ETH").unwrap();
+            let usd = money.into_code("USD".try_into().unwrap(), &rates).unwrap();
+
+            // 1.5000000000000000015, truncated
+            assert_eq!(
+                format!("{:.18}", usd),
+                "1.500000000000000001 USD".to_string()
+            );
+            assert_eq!(
+                Money::with_str_code(CurrencyAmount::with_unit(1_000_000_000), "ETH")
+                    .unwrap()
+                    .into_code("USD".try_into().unwrap(), &rates),
+                Money::with_str_code(CurrencyAmount::with_unit(1_500_000_000), "USD")
+            );
+        }
+
+        #[test]
+        fn test_units_and_fraction() {
+            let money = Money::with_str_code(CurrencyAmount::with_cents(2125), "CHF").unwrap();
+            let negative = Money::with_str_code(CurrencyAmount::with_cents(-2125), "CHF").unwrap();
+            let jpy = Money::with_str_code(CurrencyAmount::with_unit(2125), "JPY").unwrap();
+
+            assert_eq!(money.units(), 21);
+            assert_eq!(money.fraction(), 25);
+            assert_eq!(negative.units(), -21);
+            assert_eq!(negative.fraction(), -25);
+            assert_eq!(jpy.units(), 2125);
+            assert_eq!(jpy.fraction(), 0);
+        }
+
+        #[test]
+        fn test_cmp_in() {
+            let rates = rates();
+            let five_chf = Money::with_str_code(CurrencyAmount::with_unit(5), "CHF").unwrap();
+            let six_usd = Money::with_str_code(CurrencyAmount::with_unit(6), "USD").unwrap();
+            let five_usd = Money::with_str_code(CurrencyAmount::with_unit(5), "USD").unwrap();
+            let five_fifty_usd =
+                Money::with_str_code(CurrencyAmount::with_cents(550), "USD").unwrap();
+
+            // 5 CHF are worth 5.50 USD
+            assert_eq!(five_chf.lt_in(&six_usd, &rates), Ok(true));
+            assert_eq!(five_chf.gt_in(&six_usd, &rates), Ok(false));
+            assert_eq!(five_chf.gt_in(&five_usd, &rates), Ok(true));
+            assert_eq!(
+                five_chf.cmp_in(&five_fifty_usd, &rates),
+                Ok(Ordering::Equal)
+            );
+            assert_eq!(six_usd.cmp_in(&five_chf, &rates), Ok(Ordering::Greater));
+        }
+
+        #[test]
+        fn test_cmp_in_missing_rate() {
+            let five_chf = Money::with_str_code(CurrencyAmount::with_unit(5), "CHF").unwrap();
+            let five_jpy = Money::with_str_code(CurrencyAmount::with_unit(5), "JPY").unwrap();
+
+            assert_eq!(
+                five_chf.cmp_in(&five_jpy, &rates()),
+                Err(Error::RateNotFound("JPY".try_into().unwrap()))
+            );
+        }
+
+        #[test]
+        fn test_amount_string() {
+            let chf = Money::with_str_code(CurrencyAmount::with_cents(2125), "CHF").unwrap();
+            let jpy = Money::with_str_code(CurrencyAmount::with_unit(2125), "JPY").unwrap();
+            let kwd = Money::with_str_code(CurrencyAmount::with_thousands(21_125), "KWD").unwrap();
+
+            assert_eq!(chf.amount_string(), "21.25".to_string());
+            assert_eq!(jpy.amount_string(), "2125".to_string());
+            assert_eq!(kwd.amount_string(), "21.125".to_string());
+        }
+
+        #[test]
+        fn test_format_with_name() {
+            let usd = Money::with_str_code(CurrencyAmount::with_unit(1), "USD").unwrap();
+            let chf = Money::with_str_code(CurrencyAmount::with_cents(2125), "CHF").unwrap();
+
+            assert_eq!(
+                usd.format_with_name(),
+                "1.00 United States dollar".to_string()
+            );
+            assert_eq!(chf.format_with_name(), "21.25 Swiss franc".to_string());
+            assert_eq!(format!("{}", usd), "1.00 USD".to_string());
+        }
+
+        #[test]
+        fn test_format_with_name_unknown_code() {
+            let money = Money::with_str_code(CurrencyAmount::with_cents(2125), "XYZ").unwrap();
+
+            assert_eq!(money.format_with_name(), "21.25 XYZ".to_string());
+        }
+
+        #[test]
+        fn test_display_symbol() {
+            let money = Money::with_str_code(CurrencyAmount::with_cents(2125), "CHF").unwrap();
+
+            assert_eq!(format!("{:#}", money), "21.25 Fr.".to_string());
+            assert_eq!(format!("{:#.0}", money), "21 Fr.".to_string());
+        }
+
+        #[test]
+        fn test_display_symbol_fallback() {
+            let money = Money::with_str_code(CurrencyAmount::with_cents(2125), "SEK").unwrap();
+
+            assert_eq!(format!("{:#}", money), "21.25 SEK".to_string());
+        }
+
         #[test]
         #[should_panic]
         fn test_display_panic() {
+            // One more decimal than AMOUNT_UNIT has
+            let precision = AMOUNT_UNIT.to_string().len();
             let money = Money::with_str_code(CurrencyAmount::with_cents(2125), "CHF").unwrap();
-            let _formatted = format!("{:.8}", money);
+            let _formatted = format!("{:.*}", precision, money);
         }
     }
 }
@@ -1,66 +1,129 @@
 use crate::{Exponent, Money, Rates};
 
 /// A generic operation trait
+///
+/// Operations and their results are `#[must_use]`: building or executing an operation and
+/// discarding it is most likely a bug.
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use monet::{CurrencyAmount, Money};
+///
+/// fn main() {
+///     let money = Money::with_str_code(CurrencyAmount::with_unit(1), "USD").unwrap();
+///     money + money;
+/// }
+/// ```
 pub trait Operation {
     /// Execute this operation agains some defined rates.
-    fn execute(self, rates: &Rates) -> Option<Money>;
+    #[must_use]
+    fn execute(self, rates: &Rates) -> Option<Money>
+    where
+        Self: Sized,
+    {
+        self.execute_ref(rates).ok()
+    }
+
+    /// Execute this operation by reference agains some defined rates.
+    /// Unlike [`execute`](#method.execute), the operation can be executed again afterwards,
+    /// for example against a different set of rates.
+    ///
+    /// Since 0.2 this is the method to implement: `execute` is provided on top of it.
+    fn execute_ref(&self, rates: &Rates) -> crate::Result<Money>;
+
+    /// Renders this operation as text, such as `((1.00 USD + 2.00 CHF) * 3)`, to inspect
+    /// the tree without executing it. Operations defined outside of this crate are rendered as
+    /// their type name unless they override this method.
+    fn explain(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
 }
 
 /// An operation adding two currencies. The output has same currency code as `A`.
+#[must_use]
 pub struct Add<A: Operation, B: Operation>(pub A, pub B);
 /// Operation subtracting two currencies. The output has same currency code as `A`.
+#[must_use]
 pub struct Sub<A: Operation, B: Operation>(pub A, pub B);
 /// Operation multiplying a money by an amount. The output has same currency code as `A`.
+#[must_use]
 pub struct Mul<A: Operation>(pub A, pub Exponent);
 /// Operation dividing a money by an amount. The output has same currency code as `A`.
+#[must_use]
 pub struct Div<A: Operation>(pub A, pub Exponent);
 
 impl<A: Operation, B: Operation> Operation for Add<A, B> {
-    fn execute(self, rates: &Rates) -> Option<Money> {
-        let money_a = self.0.execute(rates)?;
-        let money_b = self.1.execute(rates)?;
-
-        Some(Money::new(
-            money_a.amount + money_b.into_code(money_a.currency_code, rates)?.amount,
+    fn execute_ref(&self, rates: &Rates) -> crate::Result<Money> {
+        let money_a = self.0.execute_ref(rates)?;
+        let money_b = self.1.execute_ref(rates)?;
+
+        Ok(Money::new(
+            money_a.amount
+                + rates.convert_amount(
+                    money_b.amount,
+                    money_b.currency_code,
+                    money_a.currency_code,
+                )?,
             money_a.currency_code,
         ))
     }
+
+    fn explain(&self) -> String {
+        format!("({} + {})", self.0.explain(), self.1.explain())
+    }
 }
 
 impl<A: Operation, B: Operation> Operation for Sub<A, B> {
-    fn execute(self, rates: &Rates) -> Option<Money> {
-        let money_a = self.0.execute(rates)?;
-        let money_b = self.1.execute(rates)?;
-
-        Some(Money::new(
-            money_a.amount - money_b.into_code(money_a.currency_code, rates)?.amount,
+    fn execute_ref(&self, rates: &Rates) -> crate::Result<Money> {
+        let money_a = self.0.execute_ref(rates)?;
+        let money_b = self.1.execute_ref(rates)?;
+
+        Ok(Money::new(
+            money_a.amount
+                - rates.convert_amount(
+                    money_b.amount,
+                    money_b.currency_code,
+                    money_a.currency_code,
+                )?,
             money_a.currency_code,
         ))
     }
+
+    fn explain(&self) -> String {
+        format!("({} - {})", self.0.explain(), self.1.explain())
+    }
 }
 
 impl<A: Operation> Operation for Mul<A> {
-    fn execute(self, rates: &Rates) -> Option<Money> {
+    fn execute_ref(&self, rates: &Rates) -> crate::Result<Money> {
         let exponent = &self.1;
-        let money_a = self.0.execute(rates)?;
+        let money_a = self.0.execute_ref(rates)?;
 
-        Some(Money::new(
+        Ok(Money::new(
             money_a.amount * exponent.amount / 10i128.pow(u32::from(exponent.exponent)).into(),
             money_a.currency_code,
         ))
     }
+
+    fn explain(&self) -> String {
+        format!("({} * {})", self.0.explain(), self.1)
+    }
 }
 
 impl<A: Operation> Operation for Div<A> {
-    fn execute(self, rates: &Rates) -> Option<Money> {
+    fn execute_ref(&self, rates: &Rates) -> crate::Result<Money> {
         let exponent = &self.1;
-        let money_a = self.0.execute(rates)?;
+        let money_a = self.0.execute_ref(rates)?;
 
-        Some(Money::new(
+        Ok(Money::new(
             money_a.amount * 10i128.pow(u32::from(exponent.exponent)).into() / exponent.amount,
             money_a.currency_code,
         ))
     }
+
+    fn explain(&self) -> String {
+        format!("({} / {})", self.0.explain(), self.1)
+    }
 }
 
 // Impl chaining for Add
@@ -182,8 +245,12 @@ impl<_A: Operation> std::ops::Div<Exponent> for Div<_A> {
 // Impl Operation for money, to allow easier chaining
 
 impl Operation for Money {
-    fn execute(self, _rates: &Rates) -> Option<Money> {
-        Some(self)
+    fn execute_ref(&self, _rates: &Rates) -> crate::Result<Money> {
+        Ok(*self)
+    }
+
+    fn explain(&self) -> String {
+        self.to_string()
     }
 }
 
@@ -217,9 +284,10 @@ impl std::ops::Div<Exponent> for Money {
 }
 
 #[cfg(test)]
+#[deny(unused_must_use)]
 mod tests {
     use crate::rates;
-    use crate::{Exponent, Money, Operation};
+    use crate::{CurrencyAmount, Exponent, Money, Operation, Rates};
     use std::convert::TryInto;
 
     #[test]
@@ -385,4 +453,46 @@ mod tests {
 
         assert_eq!(result, Money::with_str_code((-1_000_000).into(), "USD"))
     }
+
+    #[test]
+    fn test_explain() {
+        let money1 = Money::with_str_code(CurrencyAmount::with_unit(1), "USD").unwrap();
+        let money2 = Money::with_str_code(CurrencyAmount::with_unit(2), "CHF").unwrap();
+        let money3 = Money::with_str_code(CurrencyAmount::with_cents(50), "EUR").unwrap();
+        let operation = (money1 + money2) * Exponent::new(3.into(), 0) - money3;
+
+        assert_eq!(
+            operation.explain(),
+            "(((1.00 USD + 2.00 CHF) * 3) - 0.50 EUR)".to_string()
+        );
+        assert_eq!(
+            (money1 / Exponent::new(25.into(), 1)).explain(),
+            "(1.00 USD / 2.5)".to_string()
+        );
+        assert_eq!(money1.explain(), money1.to_string());
+    }
+
+    #[test]
+    fn test_execute_ref_against_different_rates() {
+        let money1 = Money::with_str_code(1_000_000.into(), "USD").unwrap();
+        let money2 = Money::with_str_code(1_000_000.into(), "CHF").unwrap();
+        let operation = (money1 + money2) * Exponent::new(2.into(), 0);
+
+        let bid_rates = rates();
+        let ask_rates = Rates::with_rates(
+            vec![("USD", 1_000_000), ("CHF", 1_200_000)]
+                .into_iter()
+                .map(|(code, worth)| (code.try_into().unwrap(), worth.into()))
+                .collect(),
+        );
+
+        assert_eq!(
+            operation.execute_ref(&bid_rates),
+            Ok(Money::with_str_code(4_200_000.into(), "USD").unwrap())
+        );
+        assert_eq!(
+            operation.execute_ref(&ask_rates),
+            Ok(Money::with_str_code(4_400_000.into(), "USD").unwrap())
+        );
+    }
 }
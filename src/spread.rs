@@ -0,0 +1,213 @@
+use crate::{CurrencyAmount, CurrencyCode, Error, Rates};
+use std::collections::HashMap;
+
+/// A side of a quote. [`SpreadRates::convert`](struct.SpreadRates.html#method.convert) values
+/// the currency converted from at this side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The price a market maker buys at, i.e. the lower worth.
+    Bid,
+    /// The price a market maker sells at, i.e. the higher worth.
+    Ask,
+}
+
+/// Rates quoted as a `(bid, ask)` pair of worths per currency.
+/// Each side is a plain [`Rates`](struct.Rates.html), so operations can be executed against
+/// one side with [`rates`](#method.rates).
+#[derive(Debug, Clone, Default)]
+pub struct SpreadRates {
+    bid: Rates,
+    ask: Rates,
+}
+
+impl SpreadRates {
+    /// Construct a SpreadRates struct with given `(bid, ask)` worths.
+    ///
+    /// Worths aren't validated, see [`Rates::with_rates`](struct.Rates.html#method.with_rates)
+    /// and [`try_with_rates`](#method.try_with_rates).
+    pub fn with_rates(map: HashMap<CurrencyCode, (CurrencyAmount, CurrencyAmount)>) -> Self {
+        let (bid, ask) = map
+            .into_iter()
+            .map(|(code, (bid, ask))| ((code, bid), (code, ask)))
+            .unzip();
+
+        SpreadRates {
+            bid: Rates::with_rates(bid),
+            ask: Rates::with_rates(ask),
+        }
+    }
+
+    /// Construct a SpreadRates struct with given `(bid, ask)` worths, checking that every worth
+    /// is strictly positive and that no bid is above its ask.
+    /// Returns `Error::InvalidRate` otherwise.
+    pub fn try_with_rates(
+        map: HashMap<CurrencyCode, (CurrencyAmount, CurrencyAmount)>,
+    ) -> crate::Result<Self> {
+        for (code, (bid, ask)) in &map {
+            if **bid <= 0 || bid > ask {
+                return Err(Error::InvalidRate(*code));
+            }
+        }
+
+        Ok(SpreadRates::with_rates(map))
+    }
+
+    /// The rates of one side of the quotes.
+    pub fn rates(&self, side: Side) -> &Rates {
+        match side {
+            Side::Bid => &self.bid,
+            Side::Ask => &self.ask,
+        }
+    }
+
+    /// Convert `amount` between currencies. `to` is always bought at its ask, and `side` picks
+    /// the quote `from` is valued at:
+    ///
+    /// * `Side::Bid`: a client sells `from` to the market maker, so the conversion loses the
+    ///   spread of both currencies.
+    /// * `Side::Ask`: the market maker's view, valuing `from` at the price it sells it for. The
+    ///   conversion only triangulates through the asks.
+    ///
+    /// Either way a round trip never gains: converting the result back into `from` with the
+    /// same `side` gives at most `amount`. A quote with `bid == ask` converts like
+    /// [`Rates::convert_amount`](struct.Rates.html#method.convert_amount).
+    ///
+    /// Returns `Error::RateNotFound` if either `from` or `to` has no rate and `Error::Overflow`
+    /// if the conversion doesn't fit in a `CurrencyAmount`.
+    pub fn convert(
+        &self,
+        amount: CurrencyAmount,
+        from: CurrencyCode,
+        to: CurrencyCode,
+        side: Side,
+    ) -> crate::Result<CurrencyAmount> {
+        let rates_from = self.rates(side);
+        let worth_from = rates_from.worth(from).ok_or(Error::RateNotFound(from))?;
+        let worth_to = self.ask.worth(to).ok_or(Error::RateNotFound(to))?;
+
+        rates_from.convert_with_worths(amount, worth_from, to, worth_to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CurrencyAmount, Error, Side, SpreadRates};
+    use std::convert::TryInto;
+
+    fn spread_rates() -> SpreadRates {
+        SpreadRates::with_rates(
+            vec![
+                (
+                    "USD",
+                    (CurrencyAmount::with_unit(1), CurrencyAmount::with_unit(1)),
+                ),
+                (
+                    "CHF",
+                    (
+                        CurrencyAmount::with_cents(100),
+                        CurrencyAmount::with_cents(125),
+                    ),
+                ),
+            ]
+            .into_iter()
+            .map(|(code, worths)| (code.try_into().unwrap(), worths))
+            .collect(),
+        )
+    }
+
+    #[test]
+    fn test_convert() {
+        let rates = spread_rates();
+        let usd = "USD".try_into().unwrap();
+        let chf = "CHF".try_into().unwrap();
+        let amount = CurrencyAmount::with_unit(10);
+
+        assert_eq!(
+            rates.convert(amount, chf, usd, Side::Bid),
+            Ok(CurrencyAmount::with_unit(10))
+        );
+        assert_eq!(
+            rates.convert(amount, chf, usd, Side::Ask),
+            Ok(CurrencyAmount::with_cents(1250))
+        );
+        assert_eq!(
+            rates.convert(amount, usd, chf, Side::Bid),
+            Ok(CurrencyAmount::with_unit(8))
+        );
+        assert_eq!(
+            rates.convert(amount, usd, chf, Side::Ask),
+            Ok(CurrencyAmount::with_unit(8))
+        );
+        assert_eq!(
+            rates.convert(amount, usd, "JPY".try_into().unwrap(), Side::Bid),
+            Err(Error::RateNotFound("JPY".try_into().unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_convert_both_spreads() {
+        let quote = (
+            CurrencyAmount::with_cents(90),
+            CurrencyAmount::with_cents(110),
+        );
+        let rates = SpreadRates::with_rates(
+            vec![
+                ("USD".try_into().unwrap(), quote),
+                ("CHF".try_into().unwrap(), quote),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let usd = "USD".try_into().unwrap();
+        let chf = "CHF".try_into().unwrap();
+        let amount = CurrencyAmount::with_unit(99);
+
+        // 99 * 0.90 / 1.10 and 99 * 1.10 / 1.10
+        assert_eq!(
+            rates.convert(amount, usd, chf, Side::Bid),
+            Ok(CurrencyAmount::with_unit(81))
+        );
+        assert_eq!(rates.convert(amount, usd, chf, Side::Ask), Ok(amount));
+
+        for &side in &[Side::Bid, Side::Ask] {
+            for &(from, to) in &[(usd, chf), (chf, usd)] {
+                let round_trip = rates
+                    .convert(amount, from, to, side)
+                    .and_then(|converted| rates.convert(converted, to, from, side))
+                    .unwrap();
+
+                assert!(round_trip <= amount, "{:?} round trip gained", side);
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_with_rates() {
+        let crossed = vec![(
+            "CHF".try_into().unwrap(),
+            (CurrencyAmount::with_unit(2), CurrencyAmount::with_unit(1)),
+        )];
+        let negative = vec![(
+            "CHF".try_into().unwrap(),
+            (CurrencyAmount::with_unit(-1), CurrencyAmount::with_unit(1)),
+        )];
+
+        assert_eq!(
+            SpreadRates::try_with_rates(crossed.into_iter().collect()).err(),
+            Some(Error::InvalidRate("CHF".try_into().unwrap()))
+        );
+        assert_eq!(
+            SpreadRates::try_with_rates(negative.into_iter().collect()).err(),
+            Some(Error::InvalidRate("CHF".try_into().unwrap()))
+        );
+        assert!(SpreadRates::try_with_rates(
+            vec![(
+                "CHF".try_into().unwrap(),
+                (CurrencyAmount::with_unit(1), CurrencyAmount::with_unit(1)),
+            )]
+            .into_iter()
+            .collect()
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{CurrencyAmount, CurrencyCode, Error};
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql, FromSqlRow, Queryable};
+use diesel::expression::bound::Bound;
+use diesel::expression::AsExpression;
+use diesel::row::Row;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{BigInt, Nullable, VarChar};
+use std::convert::{TryFrom, TryInto};
+use std::io::Write;
+
+/// Implements what `#[derive(AsExpression, FromSqlRow)]` would for `$ty` mapped to `$sql_type`,
+/// given its `ToSql` and `FromSql` implementations. The derives define their impls inside
+/// functions, which the `non_local_definitions` lint rejects.
+macro_rules! sql_type {
+    ($ty:ty, $sql_type:ty) => {
+        impl AsExpression<$sql_type> for $ty {
+            type Expression = Bound<$sql_type, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<'a> AsExpression<$sql_type> for &'a $ty {
+            type Expression = Bound<$sql_type, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl AsExpression<Nullable<$sql_type>> for $ty {
+            type Expression = Bound<Nullable<$sql_type>, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<'a> AsExpression<Nullable<$sql_type>> for &'a $ty {
+            type Expression = Bound<Nullable<$sql_type>, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<DB: Backend> ToSql<Nullable<$sql_type>, DB> for $ty
+        where
+            Self: ToSql<$sql_type, DB>,
+        {
+            fn to_sql<W: Write>(&self, out: &mut Output<W, DB>) -> serialize::Result {
+                ToSql::<$sql_type, DB>::to_sql(self, out)
+            }
+        }
+
+        impl<ST, DB: Backend> FromSqlRow<ST, DB> for $ty
+        where
+            Self: FromSql<ST, DB>,
+        {
+            fn build_from_row<R: Row<DB>>(row: &mut R) -> deserialize::Result<Self> {
+                FromSql::<ST, DB>::from_sql(row.take())
+            }
+        }
+
+        impl<ST, DB: Backend> Queryable<ST, DB> for $ty
+        where
+            Self: FromSqlRow<ST, DB>,
+        {
+            type Row = Self;
+
+            fn build(row: Self::Row) -> Self {
+                row
+            }
+        }
+    };
+}
+
+sql_type!(CurrencyCode, VarChar);
+sql_type!(CurrencyAmount, BigInt);
+
+/// Stores a `CurrencyCode` as its three letters.
+impl<DB: Backend> ToSql<VarChar, DB> for CurrencyCode
+where
+    str: ToSql<VarChar, DB>,
+{
+    fn to_sql<W: Write>(&self, out: &mut Output<W, DB>) -> serialize::Result {
+        self.as_ref().to_sql(out)
+    }
+}
+
+/// Loads a `CurrencyCode` like `TryFrom<&str>`, failing for a malformed code.
+impl<DB: Backend> FromSql<VarChar, DB> for CurrencyCode
+where
+    String: FromSql<VarChar, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        Ok(String::from_sql(bytes)?.as_str().try_into()?)
+    }
+}
+
+/// Stores a `CurrencyAmount` as its raw fractions of a unit.
+/// Fails with `Error::Overflow` if the amount doesn't fit in an `i64`: that's about
+/// 9 * 10^12 units by default, but only about 9 units with the `high-precision` feature.
+impl<DB: Backend> ToSql<BigInt, DB> for CurrencyAmount
+where
+    i64: ToSql<BigInt, DB>,
+{
+    fn to_sql<W: Write>(&self, out: &mut Output<W, DB>) -> serialize::Result {
+        i64::try_from(**self)
+            .map_err(|_| Error::Overflow)?
+            .to_sql(out)
+    }
+}
+
+impl<DB: Backend> FromSql<BigInt, DB> for CurrencyAmount
+where
+    i64: FromSql<BigInt, DB>,
+{
+    fn from_sql(bytes: Option<&DB::RawValue>) -> deserialize::Result<Self> {
+        i64::from_sql(bytes).map(CurrencyAmount::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CurrencyAmount, CurrencyCode, AMOUNT_UNIT};
+    use diesel::dsl::sql;
+    use diesel::prelude::*;
+    use diesel::select;
+    use diesel::sql_types::{BigInt, VarChar};
+    use diesel::sqlite::SqliteConnection;
+    use std::convert::TryInto;
+
+    fn connection() -> SqliteConnection {
+        SqliteConnection::establish(":memory:").unwrap()
+    }
+
+    #[test]
+    fn test_currency_code() {
+        let connection = connection();
+        let chf: CurrencyCode = "CHF".try_into().unwrap();
+
+        assert_eq!(
+            select(chf.into_sql::<VarChar>())
+                .get_result::<CurrencyCode>(&connection)
+                .unwrap(),
+            chf
+        );
+        assert_eq!(
+            select(sql::<VarChar>("'CHF'"))
+                .get_result::<CurrencyCode>(&connection)
+                .unwrap(),
+            chf
+        );
+        assert!(select(sql::<VarChar>("'C1F'"))
+            .get_result::<CurrencyCode>(&connection)
+            .is_err());
+    }
+
+    #[test]
+    fn test_currency_amount() {
+        let connection = connection();
+        let amount = CurrencyAmount::with_cents(-125);
+
+        assert_eq!(
+            select(amount.into_sql::<BigInt>())
+                .get_result::<CurrencyAmount>(&connection)
+                .unwrap(),
+            amount
+        );
+        assert_eq!(
+            select(sql::<BigInt>(&(AMOUNT_UNIT * 3).to_string()))
+                .get_result::<CurrencyAmount>(&connection)
+                .unwrap(),
+            CurrencyAmount::with_unit(3)
+        );
+
+        let too_big = CurrencyAmount::from(i128::from(i64::MAX) + 1);
+
+        assert!(select(too_big.into_sql::<BigInt>())
+            .get_result::<CurrencyAmount>(&connection)
+            .is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use crate::Money;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [&str; 12] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+];
+
+impl Money {
+    /// Writes this money out in English words, as printed on cheques:
+    /// `12.25 USD` is written `twelve dollars and 25 cents`.
+    ///
+    /// Common currencies use their unit names. Other currencies use their
+    /// ISO 4217 name and write the part below one unit as a fraction:
+    /// `12.25 SEK` is written `twelve Swedish krona/kronor and 25/100`. Codes that are not
+    /// ISO 4217 use the code as name and
+    /// [`FALLBACK_PRECISION`](constant.FALLBACK_PRECISION.html) for the fraction.
+    ///
+    /// The whole units are always written, even when zero, as on cheques. The `minus` of a
+    /// negative amount applies to the whole amount: `-0.50 EUR` is written
+    /// `minus zero euros and 50 cents`.
+    pub fn to_words(&self) -> String {
+        let code = self.currency_code.as_ref();
+        let units = self.units();
+        let fraction = self.fraction();
+        let sign = if units < 0 || fraction < 0 {
+            "minus "
+        } else {
+            ""
+        };
+        let (units, fraction) = (units.unsigned_abs(), fraction.unsigned_abs());
+
+        match unit_names(code) {
+            Some((major, minor)) => {
+                let major = if units == 1 { major.0 } else { major.1 };
+                let words = format!("{}{} {}", sign, number_to_words(units), major);
+
+                match minor {
+                    Some(minor) if fraction != 0 => {
+                        let minor = if fraction == 1 { minor.0 } else { minor.1 };
+                        format!("{} and {} {}", words, fraction, minor)
+                    }
+                    _ => words,
+                }
+            }
+            None => {
+                let name = iso4217::alpha3(code).map_or(code, |currency| currency.name);
+                let words = format!("{}{} {}", sign, number_to_words(units), name);
+                let precision = self.default_precision();
+
+                if precision > 0 {
+                    format!(
+                        "{} and {}/{}",
+                        words,
+                        fraction,
+                        10u128.pow(precision as u32)
+                    )
+                } else {
+                    words
+                }
+            }
+        }
+    }
+}
+
+type UnitName = (&'static str, &'static str);
+
+/// Singular and plural names of the major and (if any) minor unit of a currency.
+fn unit_names(code: &str) -> Option<(UnitName, Option<UnitName>)> {
+    let dollar = (("dollar", "dollars"), Some(("cent", "cents")));
+
+    match code {
+        "USD" | "CAD" | "AUD" | "NZD" | "HKD" | "SGD" => Some(dollar),
+        "EUR" => Some((("euro", "euros"), Some(("cent", "cents")))),
+        "GBP" => Some((("pound", "pounds"), Some(("penny", "pence")))),
+        "CHF" => Some((("franc", "francs"), Some(("centime", "centimes")))),
+        "JPY" => Some((("yen", "yen"), None)),
+        _ => None,
+    }
+}
+
+/// Writes a number out in English words: `123` is written `one hundred twenty-three`.
+fn number_to_words(mut number: u128) -> String {
+    if number == 0 {
+        return ONES[0].to_string();
+    }
+
+    let mut groups = Vec::new();
+    let mut scale = 0;
+
+    while number > 0 {
+        let group = (number % 1000) as usize;
+
+        if group != 0 {
+            let words = hundreds_to_words(group);
+            groups.push(if SCALES[scale].is_empty() {
+                words
+            } else {
+                format!("{} {}", words, SCALES[scale])
+            });
+        }
+
+        number /= 1000;
+        scale += 1;
+    }
+
+    groups.reverse();
+    groups.join(" ")
+}
+
+/// Writes a number in range `1..1000` out in English words.
+fn hundreds_to_words(number: usize) -> String {
+    let (hundreds, rest) = (number / 100, number % 100);
+    let rest = match rest {
+        0 => String::new(),
+        1..=19 => ONES[rest].to_string(),
+        _ if rest % 10 == 0 => TENS[rest / 10].to_string(),
+        _ => format!("{}-{}", TENS[rest / 10], ONES[rest % 10]),
+    };
+
+    match (hundreds, rest.is_empty()) {
+        (0, _) => rest,
+        (_, true) => format!("{} hundred", ONES[hundreds]),
+        (_, false) => format!("{} hundred {}", ONES[hundreds], rest),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::number_to_words;
+    use crate::{CurrencyAmount, Money};
+
+    #[test]
+    fn test_number_to_words() {
+        assert_eq!(number_to_words(0), "zero");
+        assert_eq!(number_to_words(15), "fifteen");
+        assert_eq!(number_to_words(40), "forty");
+        assert_eq!(number_to_words(123), "one hundred twenty-three");
+        assert_eq!(number_to_words(2_000_301), "two million three hundred one");
+    }
+
+    #[test]
+    fn test_to_words() {
+        let money = Money::with_str_code(CurrencyAmount::with_cents(1225), "USD").unwrap();
+
+        assert_eq!(money.to_words(), "twelve dollars and 25 cents");
+    }
+
+    #[test]
+    fn test_to_words_singular() {
+        let money = Money::with_str_code(CurrencyAmount::with_cents(101), "GBP").unwrap();
+
+        assert_eq!(money.to_words(), "one pound and 1 penny");
+    }
+
+    #[test]
+    fn test_to_words_zero_and_negative() {
+        let zero = Money::with_str_code(CurrencyAmount::with_unit(0), "USD").unwrap();
+        let negative = Money::with_str_code(CurrencyAmount::with_cents(-50), "EUR").unwrap();
+
+        assert_eq!(zero.to_words(), "zero dollars");
+        assert_eq!(negative.to_words(), "minus zero euros and 50 cents");
+    }
+
+    #[test]
+    fn test_to_words_fallback() {
+        let sek = Money::with_str_code(CurrencyAmount::with_cents(1225), "SEK").unwrap();
+        let jpy = Money::with_str_code(CurrencyAmount::with_unit(3000), "JPY").unwrap();
+
+        assert_eq!(sek.to_words(), "twelve Swedish krona/kronor and 25/100");
+        assert_eq!(jpy.to_words(), "three thousand yen");
+    }
+
+    #[test]
+    fn test_to_words_unknown_code() {
+        let money = Money::with_str_code(CurrencyAmount::with_cents(1225), "XYZ").unwrap();
+
+        assert_eq!(money.to_words(), "twelve XYZ and 25/100");
+    }
+}